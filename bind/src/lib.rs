@@ -2,7 +2,7 @@
 //!
 //! Sometimes we are forced to write some boring code like:
 //!
-//! ```rust
+//! ```rust,ignore
 //! let foo2 = foo.clone();
 //! let bar2 = *bar;
 //! let baz2 = baz.to_owned();
@@ -18,7 +18,7 @@
 //! it can do more than `macro_rules`.
 //!
 //! # Example
-//! ```rust
+//! ```rust,ignore
 //!
 //! let f = bind!( ( foo,*bar,baz.to_owned() )
 //!     move |args| {
@@ -26,37 +26,117 @@
 //!     }
 //! );
 //! ```
+//!
+//! # Features
+//!
+//! The default `std` feature makes `rc`, `arc` and `weak` bindings refer to `::std`.
+//! Without it they refer to `::alloc`, for use in `no_std` crates, and `dbg` bindings are not supported.
+//!
+//! The "hygienic" feature gives the tokens introduced by the generated `let` statements `Span::mixed_site()`
+//! hygiene. The bound identifiers are written by the caller and keep their spans, so that the expression
+//! can refer to them.
+//!
+//! Without the "hygienic" feature, the generated `.clone()` calls carry the spans of the cloned identifiers,
+//! so that lints such as `clippy::redundant_clone` check them as if they were written by the caller.
+//!
+//! The features are those of `bind_syn`, which Cargo unifies across a build, so that they apply to every
+//! user of `bind` and `bind_syn` in the build once any crate enables them.
 
-use bind_syn::Bind;
+use bind_syn::{Bind, BindInput, BindList, Binds, parse_binds, unparenthesized};
 
 use proc_macro::TokenStream;
 
-use quote::quote;
+use quote::{ToTokens, quote};
 
 use syn::{
     Expr,
+    ExprAsync,
+    ExprBlock,
     ExprClosure,
+    Ident,
+    ItemFn,
+    Stmt,
     Token,
-    parenthesized,
-    parse::{self, Parse, ParseStream},
+    parse::{self, Parse, ParseStream, Parser},
     parse_macro_input,
-    punctuated::Punctuated,
-    token,
+    parse_quote,
 };
 
-struct BindInput {
-    paren : token::Paren,
-    binds : Punctuated<Bind,Token![,]>,
-    expr  : Expr,
+// places the prelude and the bindings where `expr` uses them.
+fn bind_into( prelude: Vec<Stmt>, binds: BindList, expr: Expr ) -> proc_macro2::TokenStream {
+    // the generated statements keep the spans of the bindings, for lints such as `unused_mut` to check them.
+    let lets = binds.iter().flat_map( Bind::to_stmts ).collect::<Vec<_>>();
+    // bindings are placed inside non-move closures and async blocks, which would otherwise borrow
+    // the locals of the wrapping block, at the front of blocks, and outside of anything else.
+    match expr {
+        Expr::Closure( ExprClosure{ attrs, lifetimes, constness, movability, asyncness,
+            capture: None, or1_token, inputs, or2_token, output, body }) => {
+            // a block body is not nested in another block, which would trigger `unused_braces`.
+            let stmts = match *body {
+                Expr::Block( ExprBlock{ attrs, label: None, block }) if attrs.is_empty() => block.stmts,
+                body => vec![ Stmt::Expr( body, None )],
+            };
+            quote!{{
+                #(#attrs)* #lifetimes #constness #movability #asyncness
+                #or1_token #inputs #or2_token #output {
+                    #(#prelude)*
+                    #(#lets)*
+                    #(#stmts)*
+                }
+            }}
+        },
+        Expr::Async( ExprAsync{ attrs, async_token, capture: None, block }) => {
+            let stmts = block.stmts;
+            quote!{{
+                #(#attrs)* #async_token {
+                    #(#prelude)*
+                    #(#lets)*
+                    #(#stmts)*
+                }
+            }}
+        },
+        Expr::Block( ExprBlock{ attrs, label, block }) => {
+            let stmts = block.stmts;
+            quote!{
+                #(#attrs)* #label {
+                    #(#prelude)*
+                    #(#lets)*
+                    #(#stmts)*
+                }
+            }
+        },
+        expr => {
+            let captures = binds.iter().filter_map( Bind::capture_stmt ).collect::<Vec<_>>();
+            let expr = capture_kept( captures, expr );
+            quote!{{
+                #(#prelude)*
+                #(#lets)*
+                #expr
+            }}
+        },
+    }
 }
 
-impl Parse for BindInput {
-    fn parse( input: ParseStream ) -> parse::Result<Self> {
-        let content;
-        let paren = parenthesized!( content in input );
-        let binds = Punctuated::parse_terminated( &content )?;
-        let expr = input.parse::<Expr>()?;
-        Ok( BindInput{ paren, binds, expr })
+// puts the statements referring to the locals of `keep` bindings in front of the body of a `move` closure
+// or an `async move` block, which would not capture the locals otherwise.
+fn capture_kept( captures: Vec<Stmt>, expr: Expr ) -> Expr {
+    if captures.is_empty() {
+        return expr;
+    }
+    match expr {
+        Expr::Closure( mut closure ) => {
+            let stmts = match *closure.body {
+                Expr::Block( ExprBlock{ attrs, label: None, block }) if attrs.is_empty() => block.stmts,
+                body => vec![ Stmt::Expr( body, None )],
+            };
+            closure.body = Box::new( parse_quote!{{ #(#captures)* #(#stmts)* }});
+            Expr::Closure( closure )
+        },
+        Expr::Async( mut async_block ) => {
+            async_block.block.stmts.splice( 0..0, captures );
+            Expr::Async( async_block )
+        },
+        expr => expr,
     }
 }
 
@@ -68,6 +148,24 @@ impl Parse for BindInput {
 /// bind!( ( a_comma_separated_list_of_var_bindings ) the_expr_that_uses_the_vars )
 ///
 /// `a_comma_separated_list_of_var_bindings` is in the form of `var_binding, another var_binding, ...`.
+/// The list can be delimited by brackets or braces instead of parentheses,
+/// as in `bind![ [ bindings ] expr ]` or `bind!{ { bindings } expr }`.
+/// Semicolons can be used as separators too, as in `var_binding; another var_binding; ...`.
+///
+/// Statements to run before the bindings can be given in braces in front of them,
+/// as in `bind!( { let guard = lock.lock(); } ( bindings ) expr )`. They are placed along with the bindings.
+///
+/// The bindings are placed inside the closure body if the expression is a closure without `move`,
+/// or inside the block if it is an async block without `move`, so that the clones are captured
+/// instead of borrowed. If the expression is a block, the bindings are placed at the front of it.
+/// Otherwise the bindings are placed before the expression. Parentheses around the expression are ignored,
+/// e.g. of `(move || ..)`. Without bindings and prelude, the expression is emitted unchanged.
+///
+/// The bindings are `let` statements in the order they are written, so that a binding refers to the locals
+/// of the bindings before it, e.g. `bind!( (a = b, c = a) .. )` binds `c` to a clone of `b`, not of the outer `a`.
+///
+/// With the `borrow` keyword in front, as in `bind!( borrow ( bindings ) || expr )`, plain `id` bindings are
+/// dropped if the expression is a closure or an async block without `move`, which borrows the variables instead.
 ///
 /// `var_binding` is in the form of:
 ///
@@ -84,41 +182,243 @@ impl Parse for BindInput {
 /// 6. `mut id = expr`, generating `let mut id = expr;`
 ///
 /// 7. `expr`, generating `let the_only_id_in_the_expr = expr;`,
-///     e.g. `bind!( (s.to_owned()) .. )` generates `let s = s.to_owned()`.
+///    e.g. `bind!( (s.to_owned()) .. )` generates `let s = s.to_owned()`.
+///    For field accesses the last field names the binding,
+///    e.g. `bind!( (self.foo.clone()) .. )` generates `let foo = self.foo.clone()`.
+///    Trailing method calls are skipped if they merely convert the value, i.e. `clone()`, `cloned()`, `collect()`,
+///    `copied()`, `into()`, `into_iter()`, `iter()`, `to_owned()`, `to_string()` and `to_vec()`, while other method
+///    calls such as `foo.len()` need an explicit name.
+///    Index expressions such as `v[0]` or `v[..n]`, ranges such as `0..n`, calls of qualified paths such as `Arc::new(x)`,
+///    struct literals such as `Config{ timeout, ..base }`, macro calls such as `vec![x]`,
+///    `if` and `match` expressions and expressions of several variables such as `a + b` need an explicit name.
 ///
 /// 8. `mut expr`, generating `let mut the_only_id_in_the_expr = expr;`
-///     e.g. `bind!( (mut s.to_owned()) .. )` generates `let mut s = s.to_owned()`.
+///    e.g. `bind!( (mut s.to_owned()) .. )` generates `let mut s = s.to_owned()`.
+///
+/// 9. `ref id`, generating `let id = &id;`
+///
+/// 10. `ref new_id = id`, generating `let new_id = &id;`
+///
+/// 11. `ref mut id`, generating `let id = &mut id;`
+///
+/// 12. `ref mut new_id = id`, generating `let new_id = &mut id;`
+///
+/// 13. `rc id`, generating `let id = ::std::rc::Rc::clone( &id );`
+///
+/// 14. `rc new_id = id`, generating `let new_id = ::std::rc::Rc::clone( &id );`
+///
+/// 15. `arc id`, generating `let id = ::std::sync::Arc::clone( &id );`
+///
+/// 16. `arc new_id = id`, generating `let new_id = ::std::sync::Arc::clone( &id );`
+///
+/// 17. `weak id`, generating `let id = ::std::rc::Rc::downgrade( &id );`
+///
+/// 18. `weak new_id = id`, generating `let new_id = ::std::rc::Rc::downgrade( &id );`
+///
+/// 19. `move id`, generating `let id = id;`
+///
+/// 20. `move new_id = id`, generating `let new_id = id;`
+///
+/// 21. `into id`, generating `let id = id.into();`
+///
+/// 22. `into id = expr`, generating `let id = (expr).into();`
+///
+/// 23. `string id`, generating `let id = id.to_string();`
+///
+/// 24. `string id = expr`, generating `let id = (expr).to_string();`
+///
+/// 25. `copy id`, generating `let id = *id;`
+///
+/// 26. `copy new_id = id`, generating `let new_id = *id;`
+///
+/// 27. `id: Type = expr`, generating `let id: Type = expr;`
+///
+/// 28. `mut id: Type = expr`, generating `let mut id: Type = expr;`
+///
+/// 29. `into id: Type`, generating `let id: Type = id.into();`
+///
+/// 30. `into id: Type = expr`, generating `let id: Type = (expr).into();`
+///
+/// 31. `(a, b) = expr`, generating `let (a, b) = expr;`
+///
+/// 32. `mut (a, b) = expr`, generating `let (mut a, mut b) = expr;`
+///
+/// 33. `Struct{ a, b } = expr`, generating `let Struct{ a, b } = expr;`
+///
+/// 34. `try id = expr`, generating `let id = (expr)?;`
+///
+/// 35. `pat = expr else { .. }`, generating `let pat = expr else { .. };`,
+///     e.g. `bind!( (Some(x) = opt else { return }) .. )` generates `let Some(x) = opt else { return };`.
+///
+/// 36. `qclone id`, generating `let id = ::core::clone::Clone::clone( &id );`,
+///     which is not affected by an inherent method named `clone`.
+///
+/// 37. `qclone new_id = id`, generating `let new_id = ::core::clone::Clone::clone( &id );`
+///
+/// 38. `clone *expr`, generating `let the_only_id_in_the_expr = (*expr).clone();`,
+///     e.g. `bind!( (clone *foo) .. )` generates `let foo = (*foo).clone();`.
+///
+/// 39. `#[attr] var_binding`, generating the `let` statement with the outer attributes,
+///     e.g. `bind!( (#[cfg(test)] foo) .. )` generates `#[cfg(test)] let foo = foo.clone();`.
+///     Attributes go before `mut` and `ref`.
+///
+/// 40. `await id = expr`, generating `let id = (expr).await;`, in async contexts only.
+///
+/// 41. `self.{ a, mut b }`, generating `let a = self.a.clone(); let mut b = self.b.clone();`,
+///     one binding for each field of `self`.
+///
+/// 42. `take new_id = id`, generating `let new_id = id;`, moving `id` instead of cloning it.
+///
+/// 43. `cast id: Type = expr`, generating `let id = (expr) as Type;`,
+///     or `let id = (id) as Type;` without `= expr`.
+///
+/// 44. `pin id = expr`, generating `let mut id = ::core::pin::pin!( expr );`,
+///     or `let mut id = ::core::pin::pin!( id );` without `= expr`.
+///
+/// 45. `dbg id = expr`, generating `let id = ::std::dbg!( expr );`,
+///     or `let id = ::std::dbg!( id.clone() );` without `= expr`, with the `std` feature only.
+///
+/// 46. `keep id`, generating `let _id = id.clone();`, keeping a clone of `id` such as a RAII guard
+///     alive in the expression without using it. A `move` closure or `async move` block
+///     is given `let _ = &_id;` in front of its body to capture the clone.
+///
+/// 47. `own id`, generating `let id = id.to_owned();`, e.g. a `String` from a `&str` or a `Vec<T>` from a `&[T]`.
+///
+/// 48. `own new_id = id`, generating `let new_id = id.to_owned();`
+///
+/// 49. `clone{ a, b, c }`, generating `let a = a.clone(); let b = b.clone(); let c = c.clone();`,
+///     the same as `a, b, c`.
+///
+/// 50. `{ a, b } = id`, generating `let a = id.clone(); let b = id.clone();`, two independent clones of `id`.
+///
+/// 51. `borrow_mut new_id = id`, generating `let mut new_id = id.borrow_mut();`, or `let mut id = id.borrow_mut();`
+///     for `borrow_mut id`. It is meant for closures without `move`, whose bindings are placed inside the body,
+///     so that the guard lives during a call only.
+///
+/// 52. `copyok var_binding`, generating the `let` statement with `#[allow( clippy::clone_on_copy )]`,
+///     for cloning a `Copy` value on purpose, e.g. `bind!( (copyok n) .. )`.
+///
+/// 53. `@<int> var_binding`, reordering the `let` statements by the integer in ascending order, e.g. `bind!( (@2 a, @1 b) .. )`
+///     generates `let b = b.clone(); let a = a.clone();`, for the drop order of guards. Bindings without `@` are `@0`,
+///     and bindings of the same priority keep their source order.
+///
+/// 54. `var_binding then expr`, generating the `let` statement followed by `expr;`, e.g. `bind!( (mut v = src then v.reserve( 10 )) .. )`
+///     generates `let mut v = src.clone(); v.reserve( 10 );`, for setting up the local before the expression uses it.
 #[proc_macro]
 pub fn bind( input: TokenStream ) -> TokenStream {
-    let BindInput{ paren, binds, expr } = parse_macro_input!( input as BindInput );
-    let _ = paren;
-    let binds = binds.iter();
-    let extrusive = if let Expr::Closure( expr_closure ) = &expr {
-        expr_closure.capture.is_some()
-    } else {
-        true
+    let BindInput{ borrow, prelude, binds, expr, .. } = match syn::parse::<BindInput>( input ) {
+        Ok(  input ) => input,
+        // the `compile_error!`s of several malformed bindings are statements of a block in expression position.
+        Err( err   ) => {
+            let errors = err.to_compile_error();
+            return quote!{{ #errors }}.into();
+        },
     };
+    // the parentheses would hide a closure, an async block or a block from `bind_into()`.
+    let expr = unparenthesized( &expr ).clone();
+    let borrowing = borrow.is_some() && matches!( &expr,
+        Expr::Closure( ExprClosure{ capture: None, .. }) | Expr::Async( ExprAsync{ capture: None, .. }));
+    let binds = binds.into_iter()
+        .filter( |bind| !( borrowing && matches!( bind, Bind::Id(_) )))
+        .collect::<BindList>();
 
-    let expanded = if extrusive {
-        quote!{{
-            #(#binds)*
-            #expr
-        }}
-    } else {
-        if let Expr::Closure( ExprClosure{ attrs, lifetimes, constness, movability, asyncness,
-            capture, or1_token, inputs, or2_token, output, body })
-            = expr {
-            quote!{{
-                #(#attrs)* #lifetimes #constness #movability #asyncness
-                #capture #or1_token #inputs #or2_token #output {
-                    #(#binds)*
-                    #body
-                }
-            }}
-        } else {
-            unreachable!();
+    // nothing to bind, the expression is kept as is rather than being moved into a block.
+    if binds.is_empty() && prelude.is_empty() {
+        return expr.into_token_stream().into();
+    }
+
+    bind_into( prelude, binds, expr ).into()
+}
+
+struct CloneAllInput {
+    binds : BindList,
+    expr  : Expr,
+}
+
+impl Parse for CloneAllInput {
+    fn parse( input: ParseStream ) -> parse::Result<Self> {
+        let mut binds = BindList::new();
+        while !input.peek( Token![=>] ) {
+            let mutability = input.parse::<Option<Token![mut]>>()?;
+            let id = input.parse::<Ident>()?;
+            binds.push( if mutability.is_some() { Bind::mut_clone_of( id )} else { Bind::clone_of( id )});
+            if !input.peek( Token![=>] ) {
+                input.parse::<Token![,]>()?;
+            }
         }
-    };
+        input.parse::<Token![=>]>()?;
+        let expr = input.parse::<Expr>()?;
+        Ok( CloneAllInput{ binds, expr })
+    }
+}
+
+/// A proc macro cloning variables by name into an expression, a restricted form of `bind!()`.
+///
+/// # Syntax
+///
+/// bind_clone_all!( a_comma_separated_list_of_ids => the_expr_that_uses_the_vars )
+///
+/// Each item is `id` or `mut id`, generating `let id = id.clone();` or `let mut id = id.clone();`.
+/// The bindings are placed the same way as `bind!()` does.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let f = bind_clone_all!( foo, mut bar => move || {
+///     // access to foo and bar
+/// });
+/// ```
+#[proc_macro]
+pub fn bind_clone_all( input: TokenStream ) -> TokenStream {
+    let CloneAllInput{ binds, expr } = parse_macro_input!( input as CloneAllInput );
+    bind_into( Vec::new(), binds, expr ).into()
+}
 
-    expanded.into()
+/// A proc macro to generate "let bindings" in the current scope, without a trailing expression.
+///
+/// The arguments are `var_binding`s in the same forms as `bind!()`, and the macro is meant to be used
+/// at statement position.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// bindings!( foo, mut bar = baz );
+/// // access to foo and bar
+/// ```
+#[proc_macro]
+pub fn bindings( input: TokenStream ) -> TokenStream {
+    let Binds( binds ) = parse_macro_input!( input as Binds );
+    let stmts = binds.iter().flat_map( Bind::to_stmts );
+    quote!( #(#stmts)* ).into()
+}
+
+/// An attribute macro inserting "let bindings" as the first statements of the function body,
+/// usually cloning arguments into the closure the function returns.
+///
+/// The attribute arguments are `var_binding`s in the same forms as `bind!()`.
+/// Other attributes and the signature of the function are kept untouched.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[bind_fn( foo, mut bar )]
+/// fn make_callback( foo: &Rc<Foo>, bar: &String ) -> impl FnMut() {
+///     move || {
+///         // access to foo and bar, cloned from the arguments
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn bind_fn( attr: TokenStream, item: TokenStream ) -> TokenStream {
+    let binds = match parse_binds.parse( attr ) {
+        Ok(  binds ) => binds,
+        // keeps the function, so that its callers do not get errors of their own.
+        Err( err   ) => {
+            let ( err, item ) = ( err.to_compile_error(), proc_macro2::TokenStream::from( item ));
+            return quote!( #err #item ).into();
+        },
+    };
+    let mut item_fn = parse_macro_input!( item as ItemFn );
+    item_fn.block.stmts.splice( 0..0, binds.iter().flat_map( Bind::to_stmts ));
+    quote!( #item_fn ).into()
 }
@@ -6,144 +6,2041 @@
 //! This is not a proc-macro library, but a library providing syntax parsing for
 //! those proc macro libraries which provide similar functionality with
 //! `crate bind`.
+//!
+//! # Features
+//!
+//! The default `std` feature makes the generated code of `rc`, `arc` and `weak` bindings
+//! refer to `::std`, otherwise `::alloc`, which requires `extern crate alloc;` in the crate
+//! using the generated code, e.g. a `no_std` crate. `dbg` bindings use `::std::dbg!` and are
+//! parsed with the `std` feature only.
+//!
+//! Cargo unifies the features of a crate across a build, so the features apply to every user of
+//! `bind_syn` in the build: if any crate enables `std`, e.g. via the default features of `bind`,
+//! every proc macro using `bind_syn` generates `::std` paths, which do not compile in a `no_std`
+//! crate. A proc macro generating code for `no_std` crates should be built without any other
+//! user of `bind_syn` enabling `std`.
+//!
+//! The "hygienic" feature gives the tokens introduced by the generated code, such as `let`, `.clone()`
+//! and the paths to `Rc` and `Arc`, `Span::mixed_site()` hygiene, so that they never resolve to items of
+//! the expression bound into. The identifiers of the bindings are resolved at `Span::call_site()` by `ToTokens`,
+//! or keep their spans with `Bind::to_stmts()`, or the expression could not refer to them.
+//! Like `std`, the feature applies to every user of `bind_syn` in the build once any crate enables it,
+//! e.g. `bind` with its "hygienic" feature.
 
-use quote::{ToTokens, quote};
+use proc_macro2::{Span, TokenTree};
+
+use quote::{ToTokens, quote, quote_spanned};
+
+use core::{
+    fmt::{self, Debug},
+    str::FromStr,
+};
 
 use syn::{
+    Arm,
+    Attribute,
+    Block,
     Expr,
     ExprAssign,
+    ExprAwait,
+    ExprBlock,
+    ExprCall,
+    ExprCast,
+    ExprClosure,
+    ExprField,
+    ExprGroup,
+    ExprMethodCall,
+    ExprParen,
     ExprPath,
+    ExprReference,
+    ExprTry,
+    ExprUnary,
+    FieldValue,
     Ident,
+    LitInt,
+    Local,
+    LocalInit,
+    MacroDelimiter,
+    Member,
+    Pat,
+    PatIdent,
+    PatType,
+    Stmt,
     Token,
+    Type,
+    UnOp,
+    braced,
+    bracketed,
+    ext::IdentExt,
+    parenthesized,
     parse::{self, Parse, ParseStream},
+    parse_quote,
+    parse_quote_spanned,
+    punctuated::{Pair, Punctuated},
+    spanned::Spanned,
+    token,
     visit::Visit,
+    visit_mut::{self, VisitMut},
 };
 
-fn extract_the_only_id_in( expr: &Expr ) -> Option<Ident> {
+/// Picks the identifier naming an auto-named binding such as `bind!( (foo.to_owned()) .. )`.
+///
+/// The identifier is picked by the structure of `expr`:
+///
+/// - a bare identifier is returned as is, while other paths such as `a::b` give `None`.
+/// - a method call gives the identifier of its receiver, e.g. `foo` for `foo.bar().baz()`.
+///   Parsing a `Bind` is stricter, accepting conversions such as `.clone()` or `.to_owned()` only.
+/// - a field access gives the named field, e.g. `bar` for `foo.bar`, or `None` for a tuple index.
+/// - an index gives `None`, since naming the element after the indexed `foo` in `foo[i]` would shadow it.
+/// - a range gives `None`, since neither of its bounds names it, e.g. `0..n`.
+/// - a struct literal gives `None`, since neither its type nor its fields name the value,
+///   e.g. `Config{ timeout, ..base }`.
+/// - a macro call gives `None`, since its path such as `vec` in `vec![x]` is not a variable.
+/// - an `if` or `match` expression gives `None`, since the identifiers in its condition or arms
+///   rarely name its value.
+/// - a function call gives the identifier found in its arguments, skipping the callee,
+///   or `None` for a call of a qualified path such as `Arc::new(x)`.
+/// - `.await`, casts, parentheses, references, `?` and unary operators give the identifier of their operand.
+///
+/// Any other expression gives the first identifier visited in it, e.g. `a` for `a + 1`,
+/// or `None` if there is no identifier at all.
+///
+/// The expression is ambiguous if it refers to more than one variable without any of them standing out,
+/// e.g. `a + b` or `f(x, y)`, giving `None`.
+///
+/// `self`, `Self`, `super` and `crate` cannot name a local, giving `None` too, e.g. for `self.to_owned()`.
+///
+/// # Example
+///
+/// ```rust
+/// use bind_syn::sole_ident;
+///
+/// let id = |src| sole_ident( &syn::parse_str( src ).unwrap() ).map( |id| id.to_string() );
+/// assert_eq!( id( "foo.to_owned().into_boxed_str()" ).as_deref(), Some( "foo" ));
+/// assert_eq!( id( "a + 1" ).as_deref(), Some( "a" ));
+/// assert_eq!( id( "a + b" ), None );
+/// assert_eq!( id( "42" ), None );
+/// assert_eq!( id( "self.to_owned()" ), None );
+/// ```
+pub fn sole_ident( expr: &Expr ) -> Option<Ident> {
+    pick_ident( expr ).ok().flatten().filter( |id| !is_reserved( id ))
+}
+
+// checks whether `id` is a path keyword which cannot name a local.
+fn is_reserved( id: &Ident ) -> bool {
+    id == "self" || id == "Self" || id == "super" || id == "crate"
+}
+
+// picks the identifier for `sole_ident()`, or fails if the expression refers to several variables
+// without any of them standing out.
+fn pick_ident( expr: &Expr ) -> parse::Result<Option<Ident>> {
     struct Extractor {
-        id  : Option<Ident>,
-        cnt : usize,
+        id   : Option<Ident>,
+        vars : Vec<Ident>,
     }
 
     impl<'a> Visit<'a> for Extractor {
         fn visit_ident( &mut self, id: &Ident ) {
-            if self.cnt == 0 && self.id.is_none() {
+            if self.id.is_none() {
                 self.id = Some( id.clone() );
             }
-            self.cnt += 1;
         }
+
+        fn visit_expr_path( &mut self, expr_path: &'a ExprPath ) {
+            if let Some( var ) = expr_path.path.get_ident().filter( |_| expr_path.qself.is_none() ) {
+                if !self.vars.contains( var ) {
+                    self.vars.push( var.clone() );
+                }
+            }
+            syn::visit::visit_expr_path( self, expr_path );
+        }
+    }
+
+    match expr {
+        Expr::Path( ExprPath{ qself: None, path, .. }) =>
+            return Ok( path.get_ident().cloned() ),
+        Expr::MethodCall( ExprMethodCall{ receiver, .. }) =>
+            return pick_ident( receiver ),
+        Expr::Field( ExprField{ member, .. }) => return Ok( match member {
+            Member::Named( id ) => Some( id.clone() ),
+            Member::Unnamed(_)  => None,
+        }),
+        Expr::Index(_) | Expr::Range(_) | Expr::Struct(_) | Expr::Macro(_) | Expr::If(_) | Expr::Match(_) => return Ok( None ),
+        Expr::Call( call ) => {
+            if is_associated_call( call ) {
+                return Ok( None );
+            }
+            let mut ids = Vec::<Ident>::new();
+            for arg in &call.args {
+                if let Some( id ) = pick_ident( arg )? {
+                    if !ids.contains( &id ) {
+                        ids.push( id );
+                    }
+                }
+            }
+            return match ids.len() {
+                0 | 1 => Ok( ids.pop() ),
+                _     => Err( ambiguity( expr, &ids )),
+            };
+        },
+        Expr::Await(     ExprAwait    { base: expr, .. }) |
+        Expr::Cast(      ExprCast     { expr,       .. }) |
+        Expr::Group(     ExprGroup    { expr,       .. }) |
+        Expr::Paren(     ExprParen    { expr,       .. }) |
+        Expr::Reference( ExprReference{ expr,       .. }) |
+        Expr::Try(       ExprTry      { expr,       .. }) |
+        Expr::Unary(     ExprUnary    { expr,       .. }) =>
+            return pick_ident( expr ),
+        _ => (),
+    }
+
+    let mut extractor = Extractor{ id: None, vars: Vec::new() };
+    extractor.visit_expr( expr );
+    if extractor.vars.len() > 1 {
+        return Err( ambiguity( expr, &extractor.vars ));
+    }
+    Ok( extractor.id )
+}
+
+fn ambiguity( expr: &Expr, vars: &[Ident] ) -> parse::Error {
+    parse::Error::new_spanned( expr, format!(
+        "cannot infer a binding name from an expression referring to both `{}` and `{}`, give an explicit name with `name = expr`",
+        vars[0], vars[1] ))
+}
+
+// checks whether `call` calls a function by a qualified path such as `Type::new(..)`, whose arguments
+// are rarely what the binding is about.
+fn is_associated_call( call: &ExprCall ) -> bool {
+    matches!( &*call.func, Expr::Path( ExprPath{ qself, path, .. })
+        if qself.is_some() || path.segments.len() > 1 )
+}
+
+// names the local of an auto-named binding, or explains why it cannot be named.
+fn auto_name( expr: &Expr ) -> parse::Result<Ident> {
+    let mut inner = expr;
+    let mut computation = None;
+    while let Expr::MethodCall( ExprMethodCall{ receiver, method, args, .. }) = inner {
+        if !args.is_empty() || !is_conversion( method ) {
+            computation = Some( method );
+        }
+        inner = receiver;
+    }
+    if let Expr::Field( ExprField{ member: Member::Unnamed(_), .. }) = inner {
+        return Err( parse::Error::new_spanned( inner,
+            "cannot infer a binding name from a tuple index, give an explicit name with `name = expr`" ));
     }
+    if let Expr::Index(_) = inner {
+        return Err( parse::Error::new_spanned( inner,
+            "cannot infer a binding name from an index expression, give an explicit name with `name = expr`" ));
+    }
+    if let Expr::Range(_) = inner {
+        return Err( parse::Error::new_spanned( inner,
+            "cannot infer a binding name from a range expression, give an explicit name with `name = expr`" ));
+    }
+    if let Expr::Struct(_) = inner {
+        return Err( parse::Error::new_spanned( inner,
+            "cannot infer a binding name from a struct literal, give an explicit name with `name = expr`" ));
+    }
+    if let Expr::If(_) | Expr::Match(_) = inner {
+        return Err( parse::Error::new_spanned( inner,
+            "cannot infer a binding name from an `if` or `match` expression, give an explicit name with `name = expr`" ));
+    }
+    if let Expr::Macro(_) = inner {
+        return Err( parse::Error::new_spanned( inner,
+            "cannot infer a binding name from a macro call, give an explicit name with `name = expr`" ));
+    }
+    if let Expr::Call( call ) = inner {
+        if is_associated_call( call ) {
+            return Err( parse::Error::new_spanned( &call.func,
+                "cannot infer a binding name from an associated function call, give an explicit name with `name = expr`" ));
+        }
+    }
+    if let Some( method ) = computation {
+        return Err( parse::Error::new( method.span(), format!(
+            "cannot infer a binding name from a call of `{method}`, give an explicit name with `name = expr`" )));
+    }
+
+    let id = pick_ident( expr )?.ok_or_else( || parse::Error::new_spanned( expr,
+        "cannot infer a binding name from this expression, give an explicit name with `name = expr`" ))?;
+    if is_reserved( &id ) {
+        return Err( parse::Error::new( id.span(),
+            format!( "cannot bind to `{id}`; give an explicit name with `name = expr`" )));
+    }
+    Ok( id )
+}
+
+// checks whether calling `method` without arguments converts the receiver into another form of the same value,
+// e.g. `.clone()` or `.to_owned()`, rather than computing something else that the receiver would misname.
+fn is_conversion( method: &Ident ) -> bool {
+    [ "clone", "cloned", "collect", "copied", "into", "into_iter", "iter", "to_owned", "to_string", "to_vec" ]
+        .iter().any( |conversion| method == conversion )
+}
 
-    let mut extractor = Extractor{ id: None, cnt: 0 };
-    extractor.visit_expr( &expr );
-    extractor.id
+// checks whether an auto-named `expr` is `id.clone()`, possibly with more `.clone()` calls,
+// which is just a verbose form of binding `id`.
+fn is_redundant_clone( id: &Ident, expr: &Expr ) -> bool {
+    let mut inner = expr;
+    let mut cloned = false;
+    while let Expr::MethodCall( ExprMethodCall{ receiver, method, args, turbofish: None, .. }) = inner {
+        if method != "clone" || !args.is_empty() {
+            return false;
+        }
+        inner = receiver;
+        cloned = true;
+    }
+    cloned && matches!( classify_expr( inner.clone() ), ExprOrIdent::Ident( receiver ) if receiver == *id )
 }
 
-enum ExprOrIdent {
+/// An expression classified by `classify_expr()`.
+pub enum ExprOrIdent {
+    /// any expression other than a bare identifier
     Expr(  Expr  ),
+    /// a bare identifier, i.e. a single-segment path without attributes, qualified self,
+    /// leading `::` or generic arguments
     Ident( Ident ),
 }
 
-fn get_expr_or_id( expr: Expr ) -> ExprOrIdent {
+/// Classifies `expr` as a bare identifier, or returns it untouched as an expression.
+///
+/// This is how `Bind` tells `new_id = id` from `id = expr`.
+///
+/// # Example
+///
+/// ```rust
+/// use bind_syn::{ExprOrIdent, classify_expr};
+///
+/// match classify_expr( syn::parse_str( "foo" ).unwrap() ) {
+///     ExprOrIdent::Ident( id ) => assert_eq!( id, "foo" ),
+///     ExprOrIdent::Expr(  _  ) => unreachable!(),
+/// }
+///
+/// for src in [ "foo.bar", "::foo", "foo::<u8>", "self::foo" ] {
+///     assert!( matches!( classify_expr( syn::parse_str( src ).unwrap() ), ExprOrIdent::Expr(_) ));
+/// }
+/// ```
+pub fn classify_expr( expr: Expr ) -> ExprOrIdent {
     if let Expr::Path( ExprPath{ attrs, qself, path }) = &expr {
-        if attrs.is_empty() && qself.is_none() {
-            if path.leading_colon.is_none() && path.segments.len() == 1 {
-                let seg = path.segments.first().unwrap();
-                if seg.arguments.is_none() {
-                    return ExprOrIdent::Ident( seg.ident.clone() );
-                }
+        if attrs.is_empty() && qself.is_none()
+            && path.leading_colon.is_none() && path.segments.len() == 1
+        {
+            let seg = path.segments.first().unwrap();
+            if seg.arguments.is_none() {
+                return ExprOrIdent::Ident( seg.ident.clone() );
             }
         }
     }
     ExprOrIdent::Expr( expr )
 }
 
+// parses `id` or `id = expr`, the operand of keyword-prefixed conversions.
+fn parse_id_or_assign( input: ParseStream ) -> parse::Result<( Ident, Option<Expr> )> {
+    let id = input.parse::<Ident>()?;
+    if input.peek( Token![=] ) {
+        input.parse::<Token![=]>()?;
+        Ok(( id, Some( input.parse::<Expr>()? )))
+    } else {
+        Ok(( id, None ))
+    }
+}
+
+// peeks `id :`, the beginning of a binding with type annotation, but not `id ::`.
+fn peek_type_annotation( input: ParseStream ) -> bool {
+    input.peek( Ident ) && input.peek2( Token![:] ) && !input.peek2( Token![::] )
+}
+
+// parses `id: Type = expr`, the initializer being optional if `optional_init` is true.
+fn parse_typed( input: ParseStream, optional_init: bool ) -> parse::Result<( Ident, Type, Option<Expr> )> {
+    let id = input.parse::<Ident>()?;
+    input.parse::<Token![:]>()?;
+    let ty = input.parse::<Type>()?;
+    if optional_init && !input.peek( Token![=] ) {
+        return Ok(( id, ty, None ));
+    }
+    input.parse::<Token![=]>()?;
+    Ok(( id, ty, Some( input.parse::<Expr>()? )))
+}
+
+// checks whether the pattern binds `id`.
+fn pat_binds( pat: &Pat, id: &Ident ) -> bool {
+    struct Finder<'a> {
+        id    : &'a Ident,
+        found : bool,
+    }
+
+    impl<'a> Visit<'a> for Finder<'_> {
+        fn visit_pat_ident( &mut self, pat_ident: &'a PatIdent ) {
+            self.found |= pat_ident.ident == *self.id;
+            syn::visit::visit_pat_ident( self, pat_ident );
+        }
+    }
+
+    let mut finder = Finder{ id, found: false };
+    finder.visit_pat( pat );
+    finder.found
+}
+
+// renames the variable `from` referred in `expr` to `to`, except where `from` is rebound.
+fn rename_refs_in( expr: &mut Expr, from: &Ident, to: &Ident ) {
+    struct Renamer<'a> {
+        from : &'a Ident,
+        to   : &'a Ident,
+    }
+
+    impl VisitMut for Renamer<'_> {
+        fn visit_expr_path_mut( &mut self, expr_path: &mut ExprPath ) {
+            if expr_path.qself.is_none() && expr_path.path.is_ident( self.from ) {
+                let ident = &mut expr_path.path.segments[0].ident;
+                let span = ident.span();
+                *ident = self.to.clone();
+                ident.set_span( span );
+            }
+        }
+
+        fn visit_field_value_mut( &mut self, field_value: &mut FieldValue ) {
+            // `Struct{ from }` becomes `Struct{ from: to }`.
+            if field_value.colon_token.is_none()
+                && matches!( &field_value.expr, Expr::Path( expr_path ) if expr_path.path.is_ident( self.from ))
+            {
+                field_value.colon_token = Some( Token![:]( field_value.member.span() ));
+            }
+            visit_mut::visit_field_value_mut( self, field_value );
+        }
+
+        fn visit_expr_closure_mut( &mut self, closure: &mut ExprClosure ) {
+            if !closure.inputs.iter().any( |pat| pat_binds( pat, self.from )) {
+                visit_mut::visit_expr_closure_mut( self, closure );
+            }
+        }
+
+        fn visit_block_mut( &mut self, block: &mut Block ) {
+            for stmt in &mut block.stmts {
+                match stmt {
+                    Stmt::Local( local ) => {
+                        if let Some( init ) = &mut local.init {
+                            self.visit_local_init_mut( init );
+                        }
+                        if pat_binds( &local.pat, self.from ) {
+                            return;
+                        }
+                    },
+                    stmt => self.visit_stmt_mut( stmt ),
+                }
+            }
+        }
+
+        fn visit_arm_mut( &mut self, arm: &mut Arm ) {
+            if !pat_binds( &arm.pat, self.from ) {
+                visit_mut::visit_arm_mut( self, arm );
+            }
+        }
+    }
+
+    Renamer{ from, to }.visit_expr_mut( expr );
+}
+
+// parses a destructuring pattern such as `(a, b)` followed by `=`, or nothing.
+fn parse_destructuring_pat( input: ParseStream ) -> parse::Result<Option<Pat>> {
+    let fork = input.fork();
+    match Pat::parse_single( &fork ) {
+        Ok( Pat::Ident( PatIdent{ subpat: None, .. }) | Pat::Path(_) ) | Err(_) => Ok( None ),
+        Ok(_) => if fork.peek( Token![=] ) && !fork.peek( Token![==] ) {
+            Pat::parse_single( input ).map( Some )
+        } else {
+            Ok( None )
+        },
+    }
+}
+
+// makes every identifier bound by the pattern mutable.
+fn mut_pat( span: Span, pat: &Pat ) -> Pat {
+    struct MakeMut( Span );
+
+    impl VisitMut for MakeMut {
+        fn visit_pat_ident_mut( &mut self, pat_ident: &mut PatIdent ) {
+            if pat_ident.by_ref.is_none() {
+                pat_ident.mutability = Some( Token![mut]( self.0 ));
+            }
+            visit_mut::visit_pat_ident_mut( self, pat_ident );
+        }
+    }
+
+    let mut pat = pat.clone();
+    MakeMut( span ).visit_pat_mut( &mut pat );
+    pat
+}
+
+// resolves every identifier bound by the pattern at `resolution`, `None` keeping their spans.
+fn resolved_pat( resolution: Option<Span>, pat: &Pat ) -> Pat {
+    struct Resolve( Option<Span> );
+
+    impl VisitMut for Resolve {
+        fn visit_pat_ident_mut( &mut self, pat_ident: &mut PatIdent ) {
+            pat_ident.ident = resolved( self.0, &pat_ident.ident );
+            visit_mut::visit_pat_ident_mut( self, pat_ident );
+        }
+    }
+
+    let mut pat = pat.clone();
+    Resolve( resolution ).visit_pat_mut( &mut pat );
+    pat
+}
+
+// the identifier resolved at `resolution`, located at `id` for diagnostics, `None` keeping its span.
+fn resolved( resolution: Option<Span>, id: &Ident ) -> Ident {
+    let mut id = id.clone();
+    id.set_span( resolve( resolution, id.span() ));
+    id
+}
+
+// names the local of `Bind::Keep`, prefixing an underscore to the name of a raw identifier without `r#`.
+// The name is introduced by the generated code, since the user's code never refers to it.
+fn kept_ident( resolution: Option<Span>, id: &Ident ) -> Ident {
+    Ident::new( &format!( "_{}", id.unraw() ), hygiene( resolution, id.span() ))
+}
+
+// the span of the tokens introduced by the generated code, located at `span` for diagnostics,
+// with `Span::mixed_site()` hygiene if the "hygienic" feature is enabled, or resolved at `resolution` otherwise.
+// Cargo unifies the feature across the build, so enabling it for one user of `bind_syn` enables it for all of them.
+fn hygiene( resolution: Option<Span>, span: Span ) -> Span {
+    if cfg!( feature = "hygienic" ) {
+        Span::mixed_site().located_at( span )
+    } else {
+        resolve( resolution, span )
+    }
+}
+
+// the span located at `span` with the hygiene of `resolution`, `None` keeping `span`.
+fn resolve( resolution: Option<Span>, span: Span ) -> Span {
+    resolution.map_or( span, |resolution| resolution.located_at( span ))
+}
+
+// the crate the generated code refers to for `Rc` and `Arc`.
+fn alloc_crate( span: Span ) -> proc_macro2::TokenStream {
+    if cfg!( feature = "std" ) {
+        quote_spanned!{ span=> ::std }
+    } else {
+        quote_spanned!{ span=> ::alloc }
+    }
+}
+
+// peeks a contextual keyword such as `rc` which must be followed by an identifier,
+// so that a variable of the same name is still parsed as a plain binding.
+fn peek_keyword( input: ParseStream, keyword: &str ) -> bool {
+    input.cursor().ident()
+        .is_some_and( |( id, rest )| id == keyword && rest.ident().is_some() )
+}
+
+// parses `id` or `id = id0`, the operand of keyword-prefixed bindings.
+fn parse_id_or_rename( input: ParseStream ) -> parse::Result<( Ident, Option<Ident> )> {
+    let id = input.parse::<Ident>()?;
+    if input.peek( Token![=] ) {
+        input.parse::<Token![=]>()?;
+        Ok(( id, Some( input.parse::<Ident>()? )))
+    } else {
+        Ok(( id, None ))
+    }
+}
+
+// parses a binding prefixed with a keyword such as `rc` or `move`, or returns `None` without consuming
+// any token if there is no such keyword.
+fn parse_keyword_form( input: ParseStream ) -> parse::Result<Option<Bind>> {
+    if peek_keyword( input, "rc" ) {
+        input.parse::<Ident>()?;
+        return Ok( Some( match parse_id_or_rename( input )? {
+            ( id, None       ) => Bind::Rc(   id      ),
+            ( id, Some( id0 )) => Bind::RcId( id, id0 ),
+        }));
+    }
+
+    if peek_keyword( input, "arc" ) {
+        input.parse::<Ident>()?;
+        return Ok( Some( match parse_id_or_rename( input )? {
+            ( id, None       ) => Bind::Arc(   id      ),
+            ( id, Some( id0 )) => Bind::ArcId( id, id0 ),
+        }));
+    }
+
+    if peek_keyword( input, "weak" ) {
+        input.parse::<Ident>()?;
+        return Ok( Some( match parse_id_or_rename( input )? {
+            ( id, None       ) => Bind::Weak(   id      ),
+            ( id, Some( id0 )) => Bind::WeakId( id, id0 ),
+        }));
+    }
+
+    if input.peek( Token![move] ) && input.peek2( Ident ) {
+        input.parse::<Token![move]>()?;
+        return Ok( Some( match parse_id_or_rename( input )? {
+            ( id, None       ) => Bind::Move(   id      ),
+            ( id, Some( id0 )) => Bind::MoveId( id, id0 ),
+        }));
+    }
+
+    if peek_keyword( input, "into" ) {
+        input.parse::<Ident>()?;
+        if peek_type_annotation( input ) {
+            let ( id, ty, expr ) = parse_typed( input, true )?;
+            let expr = match expr {
+                Some( expr ) => parse_quote!{ (#expr).into() },
+                None         => parse_quote!{ #id.into() },
+            };
+            return Ok( Some( Bind::IdTypeExpr( id, ty, expr )));
+        }
+        return Ok( Some( match parse_id_or_assign( input )? {
+            ( id, None        ) => Bind::Into(   id       ),
+            ( id, Some( expr )) => Bind::IntoId( id, expr ),
+        }));
+    }
+
+    if peek_keyword( input, "string" ) {
+        input.parse::<Ident>()?;
+        return Ok( Some( match parse_id_or_assign( input )? {
+            ( id, None        ) => Bind::ToString(   id       ),
+            ( id, Some( expr )) => Bind::ToStringId( id, expr ),
+        }));
+    }
+
+    if peek_keyword( input, "copy" ) {
+        input.parse::<Ident>()?;
+        return Ok( Some( match parse_id_or_rename( input )? {
+            ( id, None       ) => Bind::Copy(   id      ),
+            ( id, Some( id0 )) => Bind::CopyId( id, id0 ),
+        }));
+    }
+
+    if input.peek( Token![try] ) && input.peek2( Ident ) {
+        input.parse::<Token![try]>()?;
+        let id = input.parse::<Ident>()?;
+        input.parse::<Token![=]>()?;
+        return Ok( Some( Bind::Try( id, input.parse::<Expr>()? )));
+    }
+
+    if input.peek( Token![await] ) && input.peek2( Ident ) {
+        input.parse::<Token![await]>()?;
+        let id = input.parse::<Ident>()?;
+        input.parse::<Token![=]>()?;
+        return Ok( Some( Bind::Await( id, input.parse::<Expr>()? )));
+    }
+
+    if peek_keyword( input, "qclone" ) {
+        input.parse::<Ident>()?;
+        return Ok( Some( match parse_id_or_rename( input )? {
+            ( id, None       ) => Bind::QClone(   id      ),
+            ( id, Some( id0 )) => Bind::QCloneId( id, id0 ),
+        }));
+    }
+
+    if input.cursor().ident().is_some_and( |( id, _ )| id == "clone" ) && input.peek2( Token![*] ) {
+        input.parse::<Ident>()?;
+        return match input.parse::<Expr>()? {
+            Expr::Unary( ExprUnary{ op: UnOp::Deref(_), expr, .. }) =>
+                Ok( Some( Bind::DerefClone( auto_name( &expr )?, *expr ))),
+            expr => Err( parse::Error::new_spanned( expr, "expected `clone *expr`" )),
+        };
+    }
+
+    // `take new = src` is the rename form of `move`.
+    if peek_keyword( input, "take" ) {
+        input.parse::<Ident>()?;
+        let id = input.parse::<Ident>()?;
+        input.parse::<Token![=]>()?;
+        return Ok( Some( Bind::MoveId( id, input.parse::<Ident>()? )));
+    }
+
+    if peek_keyword( input, "cast" ) {
+        input.parse::<Ident>()?;
+        let ( id, ty, expr ) = parse_typed( input, true )?;
+        let expr = expr.unwrap_or_else( || parse_quote!( #id ));
+        return Ok( Some( Bind::Cast( id, ty, expr )));
+    }
+
+    if peek_keyword( input, "pin" ) {
+        input.parse::<Ident>()?;
+        let ( id, expr ) = parse_id_or_assign( input )?;
+        let expr = expr.unwrap_or_else( || parse_quote!( #id ));
+        return Ok( Some( Bind::Pin( id, expr )));
+    }
+
+    if peek_keyword( input, "own" ) {
+        input.parse::<Ident>()?;
+        return Ok( Some( match parse_id_or_rename( input )? {
+            ( id, None       ) => Bind::Owned(   id      ),
+            ( id, Some( id0 )) => Bind::OwnedId( id, id0 ),
+        }));
+    }
+
+    if peek_keyword( input, "borrow_mut" ) {
+        input.parse::<Ident>()?;
+        let ( id, id0 ) = parse_id_or_rename( input )?;
+        let id0 = id0.unwrap_or_else( || id.clone() );
+        return Ok( Some( Bind::BorrowMut( id, id0 )));
+    }
+
+    // `copyok` is a shorthand of the attribute allowing clones of `Copy` values.
+    if peek_keyword( input, "copyok" ) {
+        let span = input.parse::<Ident>()?.span();
+        let allow = parse_quote_spanned!{ span=> #[allow( clippy::clone_on_copy )] };
+        return Ok( Some( Bind::Attrs( vec![ allow ], Box::new( input.parse()? ))));
+    }
+
+    if peek_keyword( input, "keep" ) {
+        input.parse::<Ident>()?;
+        return Ok( Some( Bind::Keep( input.parse::<Ident>()? )));
+    }
+
+    if peek_keyword( input, "dbg" ) {
+        let keyword = input.parse::<Ident>()?;
+        if !cfg!( feature = "std" ) {
+            return Err( parse::Error::new( keyword.span(), "`dbg` bindings require the \"std\" feature" ));
+        }
+        let ( id, expr ) = parse_id_or_assign( input )?;
+        let expr = expr.unwrap_or_else( || parse_quote!( #id.clone() ));
+        return Ok( Some( Bind::Dbg( id, expr )));
+    }
+
+    Ok( None )
+}
+
 /// The definition of let-binding shorthands.
+///
+/// `Debug` and `PartialEq` are implemented on the generated `let` statement rendered by `ToTokens`,
+/// so that `syn`'s "extra-traits" feature is not required.
+#[derive( Clone )]
 pub enum Bind {
     /// generates `let id = id.clone();`
-       Id(     Ident              ),
+               Id(              Ident              ),
     /// generates `let mut id = id.clone();`
-    MutId(     Ident              ),
+            MutId( Token![mut], Ident              ),
     /// generates `let id = id0.clone();`
-       IdId(   Ident, Ident       ),
+             IdId(              Ident, Ident       ),
     /// generates `let mut id = id0.clone();`
-    MutIdId(   Ident, Ident       ),
+          MutIdId( Token![mut], Ident, Ident       ),
     /// generates `let id = expr;`
-       IdExpr( Ident,        Expr ),
+           IdExpr(              Ident,        Expr ),
     /// generates `let mut id = expr;`
-    MutIdExpr( Ident,        Expr ),
+        MutIdExpr( Token![mut], Ident,        Expr ),
     /// generates `let id_extracted_from_expr = expr;`
-         Expr( Ident,        Expr ),
+             Expr(              Ident,        Expr ),
     /// generates `let mut id_extracted_from_expr = expr;`
-      MutExpr( Ident,        Expr ),
+          MutExpr( Token![mut], Ident,        Expr ),
+    /// generates `let id = &id;`
+              Ref(              Ident              ),
+    /// generates `let id = &id0;`
+            RefId(              Ident, Ident       ),
+    /// generates `let id = &mut id;`
+           RefMut(              Ident              ),
+    /// generates `let id = &mut id0;`
+         RefMutId(              Ident, Ident       ),
+    /// generates `let id = ::std::rc::Rc::clone( &id );`
+               Rc(              Ident              ),
+    /// generates `let id = ::std::rc::Rc::clone( &id0 );`
+             RcId(              Ident, Ident       ),
+    /// generates `let id = ::std::sync::Arc::clone( &id );`
+              Arc(              Ident              ),
+    /// generates `let id = ::std::sync::Arc::clone( &id0 );`
+            ArcId(              Ident, Ident       ),
+    /// generates `let id = ::std::rc::Rc::downgrade( &id );`
+             Weak(              Ident              ),
+    /// generates `let id = ::std::rc::Rc::downgrade( &id0 );`
+           WeakId(              Ident, Ident       ),
+    /// generates `let id = id;`
+             Move(              Ident              ),
+    /// generates `let id = id0;`, parsed from `move id = id0` or `take id = id0`
+           MoveId(              Ident, Ident       ),
+    /// generates `let id = id.into();`
+             Into(              Ident              ),
+    /// generates `let id = (expr).into();`
+           IntoId(              Ident,        Expr ),
+    /// generates `let id = id.to_string();`
+         ToString(              Ident              ),
+    /// generates `let id = (expr).to_string();`
+       ToStringId(              Ident,        Expr ),
+    /// generates `let id: Type = expr;`
+       IdTypeExpr(              Ident, Type,  Expr ),
+    /// generates `let mut id: Type = expr;`
+    MutIdTypeExpr( Token![mut], Ident, Type,  Expr ),
+    /// generates `let pat = expr;`, e.g. `let (a, b) = expr;` or `let Point{ x, y } = expr;`
+              Pat(              Pat,          Expr ),
+    /// generates `let pat = expr;` with every identifier in `pat` bound as `mut`, e.g. `let (mut a, mut b) = expr;`
+           MutPat( Token![mut], Pat,          Expr ),
+    /// generates `let id = *id;`
+             Copy(              Ident              ),
+    /// generates `let id = *id0;`
+           CopyId(              Ident, Ident       ),
+    /// generates `let id = (expr)?;`
+              Try(              Ident,        Expr ),
+    /// generates `let pat = expr else { .. };`, e.g. `let Some(x) = expr else { return };`
+          PatElse(              Pat,          Expr, Block ),
+    /// generates `let id = ::core::clone::Clone::clone( &id );`
+           QClone(              Ident              ),
+    /// generates `let id = ::core::clone::Clone::clone( &id0 );`
+         QCloneId(              Ident, Ident       ),
+    /// generates `let id_extracted_from_expr = (*expr).clone();`
+       DerefClone(              Ident,        Expr ),
+    /// generates `let id = (expr).await;`
+            Await(              Ident,        Expr ),
+    /// generates `let id = (expr) as Type;`
+             Cast(              Ident, Type,  Expr ),
+    /// generates `let mut id = ::core::pin::pin!( expr );`, or `let mut id = ::core::pin::pin!( id );` without `= expr`
+              Pin(              Ident,        Expr ),
+    /// generates `let id = ::std::dbg!( expr );`, or `let id = ::std::dbg!( id.clone() );` without `= expr`,
+    /// parsed with the "std" feature only
+              Dbg(              Ident,        Expr ),
+    /// generates `let _id = id.clone();`, keeping a clone of `id` alive such as a RAII guard without using it
+             Keep(              Ident              ),
+    /// generates `let id = id.to_owned();`, e.g. a `String` from a `&str` or a `Vec<T>` from a `&[T]`
+            Owned(              Ident              ),
+    /// generates `let id = id0.to_owned();`
+          OwnedId(              Ident, Ident       ),
+    /// generates `let mut id = id0.borrow_mut();`, parsed from `borrow_mut id = id0` or `borrow_mut id` for `id0` being `id`
+        BorrowMut(              Ident, Ident       ),
+    /// generates the `let` statement of the inner binding followed by `expr;`, parsed from `binding then expr`,
+    /// e.g. `let mut v = src.clone(); v.reserve( 10 );`
+         ThenStmt(              Box<Bind>, Expr ),
+    /// generates the `let` statement of the inner binding with outer attributes, e.g. `#[cfg(test)] let id = id.clone();`
+            Attrs(              Vec<Attribute>, Box<Bind> ),
+}
+
+/// How the generated local gets its value, returned by `Bind::binding_mode()`.
+#[derive( Clone, Copy, Debug, PartialEq, Eq )]
+pub enum BindingMode {
+    /// a new value made from a borrow of the source identifier, e.g. `id.clone()`, `Rc::clone( &id )` or `*id`
+    Clone,
+    /// the source identifier is moved, e.g. `id` or `id.into()`
+    Move,
+    /// a shared reference to the source identifier, i.e. `&id`
+    Ref,
+    /// a mutable reference to the source identifier, i.e. `&mut id`, or a guard such as `id.borrow_mut()`
+    RefMut,
+    /// the value of an expression written by the caller, whose ownership is up to the expression
+    Expr,
+}
+
+impl Bind {
+    /// Creates `Bind::Id`, generating `let id = id.clone();`
+    pub fn clone_of( id: Ident ) -> Self { Bind::Id( id )}
+
+    /// Creates `Bind::MutId`, generating `let mut id = id.clone();`
+    pub fn mut_clone_of( id: Ident ) -> Self { Bind::MutId( Token![mut]( id.span() ), id )}
+
+    /// Creates `Bind::IdId`, generating `let new = src.clone();`
+    pub fn rename( new: Ident, src: Ident ) -> Self { Bind::IdId( new, src )}
+
+    /// Creates `Bind::MutIdId`, generating `let mut new = src.clone();`
+    pub fn mut_rename( new: Ident, src: Ident ) -> Self { Bind::MutIdId( Token![mut]( new.span() ), new, src )}
+
+    /// Creates `Bind::IdExpr`, generating `let id = expr;`
+    pub fn assign( id: Ident, expr: Expr ) -> Self { Bind::IdExpr( id, expr )}
+
+    /// Creates `Bind::MutIdExpr`, generating `let mut id = expr;`
+    pub fn mut_assign( id: Ident, expr: Expr ) -> Self { Bind::MutIdExpr( Token![mut]( id.span() ), id, expr )}
+
+    /// The name of the generated local, which is the extracted one for `Bind::Expr` and `Bind::MutExpr`,
+    /// or `None` for destructuring patterns and `Bind::Keep`, whose underscore-prefixed local is not to be used.
+    pub fn ident( &self ) -> Option<&Ident> {
+        Some( match self {
+            Bind::Id(            id       ) |
+            Bind::MutId(      _, id       ) |
+            Bind::IdId(          id, _    ) |
+            Bind::MutIdId(    _, id, _    ) |
+            Bind::IdExpr(        id, _    ) |
+            Bind::MutIdExpr(  _, id, _    ) |
+            Bind::Expr(          id, _    ) |
+            Bind::MutExpr(    _, id, _    ) |
+            Bind::Ref(           id       ) |
+            Bind::RefId(         id, _    ) |
+            Bind::RefMut(        id       ) |
+            Bind::RefMutId(      id, _    ) |
+            Bind::Rc(            id       ) |
+            Bind::RcId(          id, _    ) |
+            Bind::Arc(           id       ) |
+            Bind::ArcId(         id, _    ) |
+            Bind::Weak(          id       ) |
+            Bind::WeakId(        id, _    ) |
+            Bind::Move(          id       ) |
+            Bind::MoveId(        id, _    ) |
+            Bind::Into(          id       ) |
+            Bind::IntoId(        id, _    ) |
+            Bind::ToString(      id       ) |
+            Bind::ToStringId(    id, _    ) |
+            Bind::IdTypeExpr(    id, _, _ ) |
+            Bind::MutIdTypeExpr( _, id, _, _ ) |
+            Bind::Copy(          id       ) |
+            Bind::CopyId(        id, _    ) |
+            Bind::Try(           id, _    ) |
+            Bind::QClone(        id       ) |
+            Bind::QCloneId(      id, _    ) |
+            Bind::DerefClone(    id, _    ) |
+            Bind::Await(         id, _    ) |
+            Bind::Cast(          id, _, _ ) |
+            Bind::Pin(           id, _    ) |
+            Bind::Dbg(           id, _    ) |
+            Bind::Owned(         id       ) |
+            Bind::OwnedId(       id, _    ) |
+            Bind::BorrowMut(     id, _    ) => id,
+            Bind::Pat(           ..       ) |
+            Bind::MutPat(        ..       ) |
+            Bind::PatElse(       ..       ) |
+            Bind::Keep(          ..       ) => return None,
+            Bind::ThenStmt(      bind, _  ) |
+            Bind::Attrs(         _, bind  ) => return bind.ident(),
+        })
+    }
+
+    /// Returns `true` if the generated local is declared as `let mut`.
+    pub fn is_mut( &self ) -> bool {
+        if let Bind::Attrs( _, bind ) | Bind::ThenStmt( bind, _ ) = self {
+            return bind.is_mut();
+        }
+        matches!( self,
+            Bind::MutId(         .. ) |
+            Bind::MutIdId(       .. ) |
+            Bind::MutIdExpr(     .. ) |
+            Bind::MutExpr(       .. ) |
+            Bind::MutIdTypeExpr( .. ) |
+            Bind::MutPat(        .. ) |
+            Bind::Pin(           .. ) |
+            Bind::BorrowMut(     .. ) )
+    }
+
+    /// Returns `true` if the generated `let` statement clones a value, i.e. calls `Clone::clone()` on it.
+    ///
+    /// The bindings of `rc` and `arc` only increment reference counts, and the expressions written by the
+    /// caller are not inspected, so that `Bind::IdExpr` of `foo.clone()` returns `false`.
+    pub fn needs_clone( &self ) -> bool {
+        if let Bind::Attrs( _, bind ) | Bind::ThenStmt( bind, _ ) = self {
+            return bind.needs_clone();
+        }
+        matches!( self,
+            Bind::Id(         .. ) |
+            Bind::MutId(      .. ) |
+            Bind::IdId(       .. ) |
+            Bind::MutIdId(    .. ) |
+            Bind::QClone(     .. ) |
+            Bind::QCloneId(   .. ) |
+            Bind::DerefClone( .. ) |
+            Bind::Keep(       .. ) )
+    }
+
+    /// Classifies how the generated local gets its value, for reasoning about the ownership of the source.
+    pub fn binding_mode( &self ) -> BindingMode {
+        match self {
+            Bind::Id(            .. ) |
+            Bind::MutId(         .. ) |
+            Bind::IdId(          .. ) |
+            Bind::MutIdId(       .. ) |
+            Bind::Rc(            .. ) |
+            Bind::RcId(          .. ) |
+            Bind::Arc(           .. ) |
+            Bind::ArcId(         .. ) |
+            Bind::Weak(          .. ) |
+            Bind::WeakId(        .. ) |
+            Bind::ToString(      .. ) |
+            Bind::Copy(          .. ) |
+            Bind::CopyId(        .. ) |
+            Bind::QClone(        .. ) |
+            Bind::QCloneId(      .. ) |
+            Bind::DerefClone(    .. ) |
+            Bind::Keep(          .. ) |
+            Bind::Owned(         .. ) |
+            Bind::OwnedId(       .. ) => BindingMode::Clone,
+            Bind::Move(          .. ) |
+            Bind::MoveId(        .. ) |
+            Bind::Into(          .. ) => BindingMode::Move,
+            Bind::Ref(           .. ) |
+            Bind::RefId(         .. ) => BindingMode::Ref,
+            Bind::RefMut(        .. ) |
+            Bind::RefMutId(      .. ) |
+            Bind::BorrowMut(     .. ) => BindingMode::RefMut,
+            Bind::IdExpr(        .. ) |
+            Bind::MutIdExpr(     .. ) |
+            Bind::Expr(          .. ) |
+            Bind::MutExpr(       .. ) |
+            Bind::IntoId(        .. ) |
+            Bind::ToStringId(    .. ) |
+            Bind::IdTypeExpr(    .. ) |
+            Bind::MutIdTypeExpr( .. ) |
+            Bind::Pat(           .. ) |
+            Bind::MutPat(        .. ) |
+            Bind::Try(           .. ) |
+            Bind::PatElse(       .. ) |
+            Bind::Await(         .. ) |
+            Bind::Cast(          .. ) |
+            Bind::Pin(           .. ) |
+            Bind::Dbg(           .. ) => BindingMode::Expr,
+            Bind::ThenStmt(  bind, _ ) |
+            Bind::Attrs(     _, bind ) => bind.binding_mode(),
+        }
+    }
+
+    /// The identifier the binding reads from, or `None` for variants binding from an expression.
+    ///
+    /// For variants without renaming such as `Bind::Id`, the source is the same identifier as the bound one,
+    /// e.g. `foo` for `let foo = foo.clone();`.
+    pub fn source_ident( &self ) -> Option<&Ident> {
+        match self {
+            Bind::Id(            id      ) |
+            Bind::MutId(      _, id      ) |
+            Bind::Ref(           id      ) |
+            Bind::RefMut(        id      ) |
+            Bind::Rc(            id      ) |
+            Bind::Arc(           id      ) |
+            Bind::Weak(          id      ) |
+            Bind::Move(          id      ) |
+            Bind::Into(          id      ) |
+            Bind::ToString(      id      ) |
+            Bind::Copy(          id      ) |
+            Bind::IdId(          _,  id  ) |
+            Bind::MutIdId(    _, _,  id  ) |
+            Bind::RefId(         _,  id  ) |
+            Bind::RefMutId(      _,  id  ) |
+            Bind::RcId(          _,  id  ) |
+            Bind::ArcId(         _,  id  ) |
+            Bind::WeakId(        _,  id  ) |
+            Bind::MoveId(        _,  id  ) |
+            Bind::CopyId(        _,  id  ) |
+            Bind::QClone(        id      ) |
+            Bind::Keep(          id      ) |
+            Bind::Owned(         id      ) |
+            Bind::QCloneId(      _,  id  ) |
+            Bind::OwnedId(       _,  id  ) |
+            Bind::BorrowMut(     _,  id  ) => Some( id ),
+            Bind::ThenStmt(      bind, _ ) |
+            Bind::Attrs(         _,  bind ) => bind.source_ident(),
+            _ => None,
+        }
+    }
+
+    /// The right-hand expression as written, or `None` for variants binding from an identifier.
+    ///
+    /// The source identifier of the renaming variants such as `Bind::IdId` is not an `Expr`,
+    /// and is returned by `Bind::source_ident()` instead.
+    pub fn expr( &self ) -> Option<&Expr> {
+        match self {
+            Bind::IdExpr(        _,    expr ) |
+            Bind::MutIdExpr(  _, _,    expr ) |
+            Bind::Expr(          _,    expr ) |
+            Bind::MutExpr(    _, _,    expr ) |
+            Bind::IntoId(        _,    expr ) |
+            Bind::ToStringId(    _,    expr ) |
+            Bind::IdTypeExpr(    _, _, expr ) |
+            Bind::MutIdTypeExpr( _, _, _, expr ) |
+            Bind::Pat(           _,    expr ) |
+            Bind::MutPat(     _, _,    expr ) |
+            Bind::Try(           _,    expr ) |
+            Bind::PatElse(       _,    expr, _ ) |
+            Bind::DerefClone(    _,    expr ) |
+            Bind::Await(         _,    expr ) |
+            Bind::Cast(          _, _, expr ) |
+            Bind::Pin(           _,    expr ) |
+            Bind::Dbg(           _,    expr ) => Some( expr ),
+            Bind::ThenStmt(      bind, _    ) |
+            Bind::Attrs(         _,    bind ) => bind.expr(),
+            _ => None,
+        }
+    }
+
+    /// Renames the generated local to `new`, keeping what it is bound from.
+    ///
+    /// The variants binding an identifier to itself are converted to their renaming counterparts,
+    /// e.g. `Bind::Id` to `Bind::IdId`. Note that `Bind::Expr` and `Bind::MutExpr` are converted to
+    /// `Bind::IdExpr` and `Bind::MutIdExpr`, their names no longer being extracted from the expression.
+    /// Destructuring patterns and `Bind::Keep` are left untouched.
+    pub fn rename_target( &mut self, new: Ident ) {
+        let placeholder = Bind::Id( new.clone() );
+        *self = match core::mem::replace( self, placeholder ) {
+            Bind::Id(            id           ) => Bind::IdId(          new, id       ),
+            Bind::MutId(      m, id           ) => Bind::MutIdId(    m, new, id       ),
+            Bind::IdId(          _,  id0      ) => Bind::IdId(          new, id0      ),
+            Bind::MutIdId(    m, _,  id0      ) => Bind::MutIdId(    m, new, id0      ),
+            Bind::IdExpr(        _,      expr ) => Bind::IdExpr(        new, expr     ),
+            Bind::MutIdExpr(  m, _,      expr ) => Bind::MutIdExpr(  m, new, expr     ),
+            Bind::Expr(          _,      expr ) => Bind::IdExpr(        new, expr     ),
+            Bind::MutExpr(    m, _,      expr ) => Bind::MutIdExpr(  m, new, expr     ),
+            Bind::Ref(           id           ) => Bind::RefId(         new, id       ),
+            Bind::RefId(         _,  id0      ) => Bind::RefId(         new, id0      ),
+            Bind::RefMut(        id           ) => Bind::RefMutId(      new, id       ),
+            Bind::RefMutId(      _,  id0      ) => Bind::RefMutId(      new, id0      ),
+            Bind::Rc(            id           ) => Bind::RcId(          new, id       ),
+            Bind::RcId(          _,  id0      ) => Bind::RcId(          new, id0      ),
+            Bind::Arc(           id           ) => Bind::ArcId(         new, id       ),
+            Bind::ArcId(         _,  id0      ) => Bind::ArcId(         new, id0      ),
+            Bind::Weak(          id           ) => Bind::WeakId(        new, id       ),
+            Bind::WeakId(        _,  id0      ) => Bind::WeakId(        new, id0      ),
+            Bind::Move(          id           ) => Bind::MoveId(        new, id       ),
+            Bind::MoveId(        _,  id0      ) => Bind::MoveId(        new, id0      ),
+            Bind::Into(          id           ) => Bind::IntoId(        new, parse_quote!( #id )),
+            Bind::IntoId(        _,      expr ) => Bind::IntoId(        new, expr     ),
+            Bind::ToString(      id           ) => Bind::ToStringId(    new, parse_quote!( #id )),
+            Bind::ToStringId(    _,      expr ) => Bind::ToStringId(    new, expr     ),
+            Bind::IdTypeExpr(    _,  ty, expr ) => Bind::IdTypeExpr(    new, ty, expr ),
+            Bind::MutIdTypeExpr( m, _,  ty, expr ) => Bind::MutIdTypeExpr( m, new, ty, expr ),
+            Bind::Copy(          id           ) => Bind::CopyId(        new, id       ),
+            Bind::CopyId(        _,  id0      ) => Bind::CopyId(        new, id0      ),
+            Bind::Try(           _,      expr ) => Bind::Try(           new, expr     ),
+            Bind::QClone(        id           ) => Bind::QCloneId(      new, id       ),
+            Bind::QCloneId(      _,  id0      ) => Bind::QCloneId(      new, id0      ),
+            Bind::DerefClone(    _,      expr ) => Bind::DerefClone(    new, expr     ),
+            Bind::Await(         _,      expr ) => Bind::Await(         new, expr     ),
+            Bind::Cast(          _,  ty, expr ) => Bind::Cast(          new, ty, expr ),
+            Bind::Pin(           _,      expr ) => Bind::Pin(           new, expr     ),
+            Bind::Dbg(           _,      expr ) => Bind::Dbg(           new, expr     ),
+            Bind::Owned(         id           ) => Bind::OwnedId(       new, id       ),
+            Bind::OwnedId(       _,  id0      ) => Bind::OwnedId(       new, id0      ),
+            Bind::BorrowMut(     _,  id0      ) => Bind::BorrowMut(     new, id0      ),
+            Bind::ThenStmt(  mut bind, mut expr ) => {
+                // the statement refers to the local, which is renamed along with it.
+                if let Some( old ) = bind.ident().cloned() {
+                    rename_refs_in( &mut expr, &old, &new );
+                }
+                bind.rename_target( new );
+                Bind::ThenStmt( bind, expr )
+            },
+            Bind::Attrs(     attrs, mut bind  ) => {
+                bind.rename_target( new );
+                Bind::Attrs( attrs, bind )
+            },
+            bind @ ( Bind::Pat(..) | Bind::MutPat(..) | Bind::PatElse(..) | Bind::Keep(..) ) => bind,
+        };
+    }
+
+    /// Renames the variable `from` to `to` in the right-hand expression, for the variants returning
+    /// `Some` from `Bind::expr()`.
+    ///
+    /// Closures, `let` statements and `match` arms rebinding `from` hide it, so that the references
+    /// to the rebound variable are kept. Macro invocations inside the expression are not inspected.
+    pub fn rename_refs( &mut self, from: &Ident, to: &Ident ) {
+        match self {
+            Bind::IdExpr(        _,    expr ) |
+            Bind::MutIdExpr(  _, _,    expr ) |
+            Bind::Expr(          _,    expr ) |
+            Bind::MutExpr(    _, _,    expr ) |
+            Bind::IntoId(        _,    expr ) |
+            Bind::ToStringId(    _,    expr ) |
+            Bind::IdTypeExpr(    _, _, expr ) |
+            Bind::MutIdTypeExpr( _, _, _, expr ) |
+            Bind::Pat(           _,    expr ) |
+            Bind::MutPat(     _, _,    expr ) |
+            Bind::Try(           _,    expr ) |
+            Bind::PatElse(       _,    expr, _ ) |
+            Bind::DerefClone(    _,    expr ) |
+            Bind::Await(         _,    expr ) |
+            Bind::Cast(          _, _, expr ) |
+            Bind::Pin(           _,    expr ) |
+            Bind::Dbg(           _,    expr ) => rename_refs_in( expr, from, to ),
+            Bind::ThenStmt(      bind, _    ) |
+            Bind::Attrs(         _,    bind ) => bind.rename_refs( from, to ),
+            _ => (),
+        }
+    }
+
+    /// Transforms the right-hand expression with `f`, keeping the variant, e.g. wrapping it in `Some(..)`.
+    ///
+    /// This is a no-op for the variants returning `None` from `Bind::expr()`, such as `Bind::Id` and
+    /// `Bind::IdId`, whose clone of an identifier is not an `Expr`, and `f` is not called for them.
+    pub fn map_expr( mut self, f: impl FnOnce( Expr ) -> Expr ) -> Bind {
+        if let Bind::Attrs( attrs, bind ) = self {
+            return Bind::Attrs( attrs, Box::new( bind.map_expr( f )));
+        }
+        if let Bind::ThenStmt( bind, stmt ) = self {
+            return Bind::ThenStmt( Box::new( bind.map_expr( f )), stmt );
+        }
+        match &mut self {
+            Bind::IdExpr(        _,    expr ) |
+            Bind::MutIdExpr(  _, _,    expr ) |
+            Bind::Expr(          _,    expr ) |
+            Bind::MutExpr(    _, _,    expr ) |
+            Bind::IntoId(        _,    expr ) |
+            Bind::ToStringId(    _,    expr ) |
+            Bind::IdTypeExpr(    _, _, expr ) |
+            Bind::MutIdTypeExpr( _, _, _, expr ) |
+            Bind::Pat(           _,    expr ) |
+            Bind::MutPat(     _, _,    expr ) |
+            Bind::Try(           _,    expr ) |
+            Bind::PatElse(       _,    expr, _ ) |
+            Bind::DerefClone(    _,    expr ) |
+            Bind::Await(         _,    expr ) |
+            Bind::Cast(          _, _, expr ) |
+            Bind::Pin(           _,    expr ) |
+            Bind::Dbg(           _,    expr ) => {
+                let taken = core::mem::replace( expr, Expr::Verbatim( proc_macro2::TokenStream::new() ));
+                *expr = f( taken );
+            },
+            _ => (),
+        }
+        self
+    }
+
+    /// Builds the generated `let` statement, with its tokens spanned to the binding for diagnostics to point at it.
+    ///
+    /// Only the `let` statement of `binding then expr` is built, `then_stmts()` gives the statements following it.
+    /// Use `to_stmts()` to build them all.
+    ///
+    /// Unlike `ToTokens`, the identifiers of the binding and the `mut` keyword keep their spans, for lints such as
+    /// `unused_mut` to check them as written by the caller.
+    pub fn to_local( &self ) -> Stmt {
+        self.local_with( None )
+    }
+
+    /// Builds the generated `let` statement followed by `then_stmts()`, e.g. `let mut v = src.clone(); v.reserve( 10 );`
+    /// for `mut v = src then v.reserve( 10 )`, keeping the spans of the binding like `to_local()`.
+    ///
+    /// `bind!( (foo.clone()) .. )` gives a statement in front of the `let` statement, warning that `.clone()` is redundant.
+    pub fn to_stmts( &self ) -> Vec<Stmt> {
+        let mut stmts = self.redundant_clone_warning( None ).into_iter().collect::<Vec<_>>();
+        stmts.push( self.to_local() );
+        stmts.extend( self.then_stmts() );
+        stmts
+    }
+
+    fn local_with( &self, resolution: Option<Span> ) -> Stmt {
+        if let Bind::ThenStmt( bind, _ ) = self {
+            return bind.local_with( resolution );
+        }
+        if let Bind::Attrs( outer, bind ) = self {
+            let mut stmt = bind.local_with( resolution );
+            if let Stmt::Local( local ) = &mut stmt {
+                local.attrs.splice( 0..0, outer.iter().cloned() );
+            }
+            return stmt;
+        }
+
+        let location = match self {
+            Bind::Pat( pat, _ ) | Bind::MutPat( _, pat, _ ) | Bind::PatElse( pat, _, _ ) => pat.span(),
+            Bind::Keep( id ) => id.span(),
+            _ => self.ident().map_or_else( Span::call_site, Ident::span ),
+        };
+        let span = hygiene( resolution, location );
+        let stmt_span = resolve( resolution, location );
+
+        // `mut` is located at the user's keyword, for `unused_mut` to point at it.
+        let pat_ident = |ident: Ident, mutability: Option<&Token![mut]>| Pat::Ident( PatIdent{
+            attrs      : Vec::new(),
+            by_ref     : None,
+            mutability : mutability.map( |m| Token![mut]( resolve( resolution, m.span ))),
+            ident,
+            subpat     : None,
+        });
+
+        let id_pat = |id: &Ident, mutability: Option<&Token![mut]>| pat_ident( resolved( resolution, id ), mutability );
+
+        let typed_pat = |id: &Ident, ty: &Type, mutability: Option<&Token![mut]>| Pat::Type( PatType{
+            attrs       : Vec::new(),
+            pat         : Box::new( id_pat( id, mutability )),
+            colon_token : Token![:]( stmt_span ),
+            ty          : Box::new( ty.clone() ),
+        });
+
+        let krate = alloc_crate( span );
+        let mut attrs = Vec::new();
+        let mut diverge = None;
+
+        let ( pat, init ): ( Pat, Expr ) = match self {
+            Bind::Id(            id           ) => ( id_pat( id, None    ), parse_quote_spanned!{ span=> #id .clone() }),
+            Bind::MutId(      m, id           ) => ( id_pat( id, Some( m )), parse_quote_spanned!{ span=> #id .clone() }),
+            Bind::IdId(          id, id0      ) => ( id_pat( id, None    ), parse_quote_spanned!{ hygiene( resolution, id0.span() )=> #id0.clone() }),
+            Bind::MutIdId(    m, id, id0      ) => ( id_pat( id, Some( m )), parse_quote_spanned!{ hygiene( resolution, id0.span() )=> #id0.clone() }),
+            Bind::IdExpr(        id,     expr ) => ( id_pat( id, None    ), expr.clone() ),
+            Bind::MutIdExpr(  m, id,     expr ) => ( id_pat( id, Some( m )), expr.clone() ),
+            Bind::Expr(          id,     expr ) => ( id_pat( id, None    ), expr.clone() ),
+            Bind::MutExpr(    m, id,     expr ) => ( id_pat( id, Some( m )), expr.clone() ),
+            Bind::Ref(           id           ) => ( id_pat( id, None    ), parse_quote_spanned!{ span=> &#id  }),
+            Bind::RefId(         id, id0      ) => ( id_pat( id, None    ), parse_quote_spanned!{ span=> &#id0 }),
+            Bind::RefMut(        id           ) => ( id_pat( id, None    ), parse_quote_spanned!{ span=> &mut #id  }),
+            Bind::RefMutId(      id, id0      ) => ( id_pat( id, None    ), parse_quote_spanned!{ span=> &mut #id0 }),
+            Bind::Rc(            id           ) => ( id_pat( id, None    ), parse_quote_spanned!{ span=> #krate::rc::Rc::clone( &#id  )}),
+            Bind::RcId(          id, id0      ) => ( id_pat( id, None    ), parse_quote_spanned!{ span=> #krate::rc::Rc::clone( &#id0 )}),
+            Bind::Arc(           id           ) => ( id_pat( id, None    ), parse_quote_spanned!{ span=> #krate::sync::Arc::clone( &#id  )}),
+            Bind::ArcId(         id, id0      ) => ( id_pat( id, None    ), parse_quote_spanned!{ span=> #krate::sync::Arc::clone( &#id0 )}),
+            Bind::Weak(          id           ) => ( id_pat( id, None    ), parse_quote_spanned!{ span=> #krate::rc::Rc::downgrade( &#id  )}),
+            Bind::WeakId(        id, id0      ) => ( id_pat( id, None    ), parse_quote_spanned!{ span=> #krate::rc::Rc::downgrade( &#id0 )}),
+            Bind::Move(          id           ) => {
+                attrs.push( parse_quote_spanned!{ span=> #[allow( clippy::redundant_locals )] });
+                ( id_pat( id, None    ), parse_quote_spanned!{ span=> #id  })
+            },
+            Bind::MoveId(        id, id0      ) => ( id_pat( id, None    ), parse_quote_spanned!{ span=> #id0 }),
+            Bind::Into(          id           ) => ( id_pat( id, None    ), parse_quote_spanned!{ span=> #id .into() }),
+            Bind::IntoId(        id,     expr ) => ( id_pat( id, None    ), parse_quote_spanned!{ span=> (#expr).into() }),
+            Bind::ToString(      id           ) => ( id_pat( id, None    ), parse_quote_spanned!{ span=> #id .to_string() }),
+            Bind::ToStringId(    id,     expr ) => ( id_pat( id, None    ), parse_quote_spanned!{ span=> (#expr).to_string() }),
+            Bind::IdTypeExpr(    id, ty, expr ) => ( typed_pat( id, ty, None    ), expr.clone() ),
+            Bind::MutIdTypeExpr( m, id, ty, expr ) => ( typed_pat( id, ty, Some( m )), expr.clone() ),
+            Bind::Pat(           pat,    expr ) => ( resolved_pat( resolution, pat ), expr.clone() ),
+            Bind::MutPat(     m, pat,    expr ) => ( mut_pat( resolve( resolution, m.span ), &resolved_pat( resolution, pat )), expr.clone() ),
+            Bind::Copy(          id           ) => ( id_pat( id, None    ), parse_quote_spanned!{ span=> *#id  }),
+            Bind::CopyId(        id, id0      ) => ( id_pat( id, None    ), parse_quote_spanned!{ span=> *#id0 }),
+            Bind::Try(           id,     expr ) => ( id_pat( id, None    ), parse_quote_spanned!{ span=> (#expr)? }),
+            Bind::PatElse(       pat,    expr, block ) => {
+                diverge = Some(( Token![else]( stmt_span ), Box::new( Expr::Block( ExprBlock{
+                    attrs : Vec::new(),
+                    label : None,
+                    block : block.clone(),
+                }))));
+                ( resolved_pat( resolution, pat ), expr.clone() )
+            },
+            Bind::QClone(        id           ) => ( id_pat( id, None    ), parse_quote_spanned!{ span=> ::core::clone::Clone::clone( &#id  )}),
+            Bind::QCloneId(      id, id0      ) => ( id_pat( id, None    ), parse_quote_spanned!{ span=> ::core::clone::Clone::clone( &#id0 )}),
+            Bind::DerefClone(    id,     expr ) => ( id_pat( id, None    ), parse_quote_spanned!{ span=> (*#expr).clone() }),
+            Bind::Await(         id,     expr ) => ( id_pat( id, None    ), parse_quote_spanned!{ span=> (#expr).await }),
+            Bind::Cast(          id, ty, expr ) => ( id_pat( id, None    ), parse_quote_spanned!{ span=> (#expr) as #ty }),
+            Bind::Pin(           id,     expr ) => {
+                attrs.push( parse_quote_spanned!{ span=> #[allow( unused_mut )] });
+                ( id_pat( id, Some( &Token![mut]( id.span() ))), parse_quote_spanned!{ span=> ::core::pin::pin!( #expr )})
+            },
+            Bind::Dbg(           id,     expr ) => ( id_pat( id, None    ), parse_quote_spanned!{ span=> ::std::dbg!( #expr )}),
+            Bind::Keep(          id           ) => ( pat_ident( kept_ident( resolution, id ), None ), parse_quote_spanned!{ span=> #id .clone() }),
+            Bind::Owned(         id           ) => ( id_pat( id, None    ), parse_quote_spanned!{ span=> #id .to_owned() }),
+            Bind::OwnedId(       id, id0      ) => ( id_pat( id, None    ), parse_quote_spanned!{ span=> #id0.to_owned() }),
+            Bind::BorrowMut(     id, id0      ) => {
+                attrs.push( parse_quote_spanned!{ span=> #[allow( unused_mut )] });
+                ( id_pat( id, Some( &Token![mut]( id.span() ))), parse_quote_spanned!{ span=> #id0.borrow_mut() })
+            },
+            Bind::ThenStmt(      ..           ) => unreachable!( "the statement follows the inner binding" ),
+            Bind::Attrs(         ..           ) => unreachable!( "attributes are prepended to the inner binding" ),
+        };
+
+        Stmt::Local( Local{
+            attrs,
+            let_token  : Token![let]( stmt_span ),
+            pat,
+            init       : Some( LocalInit{ eq_token: Token![=]( stmt_span ), expr: Box::new( init ), diverge }),
+            semi_token : Token![;]( stmt_span ),
+        })
+    }
+
+    /// The statement referring to the local of `Bind::Keep` with the attributes of the binding, e.g.
+    /// `let _ = &_guard;` for `keep guard`, so that a `move` closure or `async move` block captures the local
+    /// by putting the statement in front of its body. Other bindings give `None`.
+    pub fn capture_stmt( &self ) -> Option<Stmt> {
+        match self {
+            Bind::Keep( id ) => {
+                let kept = kept_ident( None, id );
+                Some( parse_quote_spanned!{ hygiene( None, id.span() )=> let _ = &#kept; })
+            },
+            Bind::Attrs( attrs, bind ) => bind.capture_stmt().map( |mut stmt| {
+                if let Stmt::Local( local ) = &mut stmt {
+                    local.attrs.splice( 0..0, attrs.iter().cloned() );
+                }
+                stmt
+            }),
+            Bind::ThenStmt( bind, _ ) => bind.capture_stmt(),
+            _ => None,
+        }
+    }
+
+    /// The statements following the generated `let` statement, i.e. `expr;` of `binding then expr`,
+    /// with the attributes of the binding. Other bindings give none.
+    pub fn then_stmts( &self ) -> Vec<Stmt> {
+        match self {
+            Bind::ThenStmt( bind, expr ) => {
+                let mut stmts = bind.then_stmts();
+                stmts.push( parse_quote_spanned!{ hygiene( None, expr.span() )=> #expr; });
+                stmts
+            },
+            Bind::Attrs( attrs, bind ) => bind.then_stmts().into_iter()
+                .map( |stmt| parse_quote!{ #(#attrs)* #stmt })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Consumes the binding into the tokens of its generated `let` statement, as rendered by `ToTokens`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bind_syn::Bind;
+    /// use quote::quote;
+    ///
+    /// let binds = [ "a", "mut b = c" ].map( |src| src.parse::<Bind>().unwrap() );
+    /// let stmts = binds.into_iter().map( Bind::into_stmt_tokens );
+    /// let block = quote!{{ #(#stmts)* a + b }};
+    /// assert_eq!( block.to_string(), quote!{{ let a = a.clone(); let mut b = c.clone(); a + b }}.to_string() );
+    /// ```
+    pub fn into_stmt_tokens( self ) -> proc_macro2::TokenStream {
+        self.to_token_stream()
+    }
+
+    /// Renders the generated statements of the binding with the identifiers of the binding and the tokens introduced
+    /// by the generated code such as `let`, `.clone()` and the paths to `Rc` and `Arc` resolved at `span`,
+    /// e.g. `Span::call_site()` or `Span::mixed_site()`, located at the binding for diagnostics.
+    /// The "hygienic" feature resolves the introduced tokens at `Span::mixed_site()` whatever `span` is.
+    ///
+    /// A binding rendered with `Span::mixed_site()` is not visible to the code of the caller of the macro.
+    /// `ToTokens` renders the binding with `Span::call_site()`.
+    pub fn to_tokens_with_span( &self, span: Span, tokens: &mut proc_macro2::TokenStream ) {
+        self.redundant_clone_warning( Some( span )).to_tokens( tokens );
+        self.local_with( Some( span )).to_tokens( tokens );
+        tokens.extend( self.then_stmts().iter().map( ToTokens::to_token_stream ));
+    }
+
+    // warns of `bind!( (foo.clone()) .. )` via a deprecated item, since proc macros cannot emit warnings.
+    fn redundant_clone_warning( &self, resolution: Option<Span> ) -> Option<Stmt> {
+        let ( attrs, bind ) = match self {
+            Bind::Attrs( attrs, bind ) => ( &attrs[..], &**bind ),
+            bind                       => ( &[][..],    bind    ),
+        };
+        let bind = match bind {
+            Bind::ThenStmt( bind, _ ) => &**bind,
+            bind                      => bind,
+        };
+        match bind {
+            Bind::Expr( id, expr ) | Bind::MutExpr( _, id, expr ) if is_redundant_clone( id, expr ) => {
+                let note = format!( "`{}` is redundant, a plain `{id}` binding clones `{id}` already",
+                    expr.to_token_stream().to_string().replace( ' ', "" ));
+                Some( parse_quote_spanned!{ hygiene( resolution, expr.span() )=>
+                    #(#attrs)*
+                    let _ = {
+                        #[deprecated( note = #note )]
+                        #[allow( non_upper_case_globals )]
+                        const redundant_clone: () = ();
+                        redundant_clone
+                    };
+                })
+            },
+            _ => None,
+        }
+    }
 }
 
 impl Parse for Bind {
     fn parse( input: ParseStream ) -> parse::Result<Self> {
-        let immutable = if input.peek( Token![mut] ) {
-            input.parse::<Token![mut]>()?;
-            false
-        } else {
-            true
+        let mut bind = parse_plain_bind( input )?;
+        while input.cursor().ident().is_some_and( |( id, _ )| id == "then" ) {
+            input.parse::<Ident>()?;
+            bind = Bind::ThenStmt( Box::new( bind ), input.parse()? );
+        }
+        Ok( bind )
+    }
+}
+
+// parses a binding without the trailing `then expr`.
+fn parse_plain_bind( input: ParseStream ) -> parse::Result<Bind> {
+    let attrs = input.call( Attribute::parse_outer )?;
+    if !attrs.is_empty() {
+        return Ok( Bind::Attrs( attrs, Box::new( input.parse()? )));
+    }
+
+    // the binding mode is an optional `ref` followed by an optional `mut`.
+    let by_ref = input.parse::<Option<Token![ref]>>()?;
+    let mutability = input.parse::<Option<Token![mut]>>()?;
+    if let Some( mutability ) = mutability {
+        if input.peek( Token![ref] ) {
+            return Err( parse::Error::new( mutability.span, "`ref` goes before `mut`, as in `ref mut id`" ));
+        }
+        if input.peek( Token![mut] ) {
+            return Err( input.error( "duplicated `mut`" ));
+        }
+        if input.peek( Token![#] ) {
+            return Err( input.error( "attributes go before `mut`" ));
+        }
+    }
+
+    if by_ref.is_some() {
+        return Ok( match ( mutability, parse_id_or_rename( input )? ) {
+            ( None,    ( id, None       )) => Bind::Ref(      id      ),
+            ( None,    ( id, Some( id0 ))) => Bind::RefId(    id, id0 ),
+            ( Some(_), ( id, None       )) => Bind::RefMut(   id      ),
+            ( Some(_), ( id, Some( id0 ))) => Bind::RefMutId( id, id0 ),
+        });
+    }
+
+    if let Some( bind ) = parse_keyword_form( input )? {
+        return match mutability {
+            None               => Ok( bind ),
+            Some( mutability ) => Err( parse::Error::new( mutability.span,
+                "`mut` is not supported with keyword-prefixed bindings" )),
         };
+    }
 
+    if let Some( pat ) = parse_destructuring_pat( input )? {
+        input.parse::<Token![=]>()?;
         let expr = input.parse::<Expr>()?;
-
-        if let Expr::Assign( expr_assign ) = &expr {
-            let ExprAssign{ attrs:_, left, eq_token, right } = expr_assign.clone();
-            let _ = eq_token;
-            if let ExprOrIdent::Ident( id ) = get_expr_or_id( *left ) {
-                match get_expr_or_id( *right ) {
-                    ExprOrIdent::Expr( expr ) =>
-                        return Ok( if immutable {
-                            Bind::IdExpr(    id, expr )
-                        } else {
-                            Bind::MutIdExpr( id, expr )
-                        }),
-                    ExprOrIdent::Ident( id0 ) =>
-                        return Ok( if immutable {
-                            Bind::IdId(      id, id0 )
-                        } else {
-                            Bind::MutIdId(   id, id0 )
-                        }),
-                }
+        if input.peek( Token![else] ) {
+            let else_token = input.parse::<Token![else]>()?;
+            if mutability.is_some() {
+                return Err( parse::Error::new_spanned( else_token,
+                    "`mut` is not supported with `else`, use `mut` inside the pattern instead" ));
             }
-        } else {
-            match get_expr_or_id( expr ) {
-                ExprOrIdent::Expr( expr ) =>
-                    match extract_the_only_id_in( &expr ) {
-                        Some( id ) =>
-                            return Ok( if immutable {
-                                Bind::Expr(    id, expr )
-                            } else {
-                                Bind::MutExpr( id, expr )
-                            }),
-                        None => (),
+            return Ok( Bind::PatElse( pat, expr, input.parse::<Block>()? ));
+        }
+        return Ok( match mutability {
+            None      => Bind::Pat(       pat, expr ),
+            Some( m ) => Bind::MutPat( m, pat, expr ),
+        });
+    }
+
+    if peek_type_annotation( input ) {
+        let ( id, ty, expr ) = parse_typed( input, false )?;
+        let expr = expr.expect( "the initializer is mandatory" );
+        return Ok( match mutability {
+            None      => Bind::IdTypeExpr(       id, ty, expr ),
+            Some( m ) => Bind::MutIdTypeExpr( m, id, ty, expr ),
+        });
+    }
+
+    let expr = input.parse::<Expr>()?;
+
+    match expr {
+        Expr::Assign( ExprAssign{ left, right, .. }) => {
+            let id = match classify_expr( *left ) {
+                ExprOrIdent::Ident( id   ) => id,
+                ExprOrIdent::Expr(  left ) => return Err( parse::Error::new_spanned(
+                    left, "expected an identifier on the left of `=`" )),
+            };
+            Ok( match ( mutability, classify_expr( *right )) {
+                ( None,      ExprOrIdent::Expr(  expr )) => Bind::IdExpr(       id, expr ),
+                ( Some( m ), ExprOrIdent::Expr(  expr )) => Bind::MutIdExpr( m, id, expr ),
+                ( None,      ExprOrIdent::Ident( id0  )) => Bind::IdId(         id, id0  ),
+                ( Some( m ), ExprOrIdent::Ident( id0  )) => Bind::MutIdId(   m, id, id0  ),
+            })
+        },
+        expr => match classify_expr( expr ) {
+            ExprOrIdent::Expr( expr ) => {
+                let id = auto_name( &expr )?;
+                Ok( match mutability {
+                    None      => Bind::Expr(       id, expr ),
+                    Some( m ) => Bind::MutExpr( m, id, expr ),
+                })
+            },
+            ExprOrIdent::Ident( id ) => Ok( match mutability {
+                None      => Bind::Id(       id ),
+                Some( m ) => Bind::MutId( m, id ),
+            }),
+        },
+    }
+}
+
+// parses `self.{ a, mut b }`, cloning the fields of `self` into locals of the same names.
+fn parse_self_fields( input: ParseStream ) -> parse::Result<Vec<Bind>> {
+    let self_token = input.parse::<Token![self]>()?;
+    input.parse::<Token![.]>()?;
+    let content;
+    braced!( content in input );
+    let mut binds = Vec::new();
+    while !content.is_empty() {
+        let mutability = content.parse::<Option<Token![mut]>>()?;
+        let id = content.parse::<Ident>()?;
+        let expr = parse_quote_spanned!{ id.span()=> #self_token.#id.clone() };
+        binds.push( match mutability {
+            None      => Bind::IdExpr(       id, expr ),
+            Some( m ) => Bind::MutIdExpr( m, id, expr ),
+        });
+        if !content.is_empty() {
+            content.parse::<Token![,]>()?;
+        }
+    }
+    Ok( binds )
+}
+
+// parses `clone{ a, b }`, cloning each identifier into a local of the same name.
+fn parse_clone_group( input: ParseStream ) -> parse::Result<Vec<Bind>> {
+    input.parse::<Ident>()?;
+    let content;
+    braced!( content in input );
+    let ids = Punctuated::<Ident,Token![,]>::parse_terminated( &content )?;
+    Ok( ids.into_iter().map( Bind::Id ).collect() )
+}
+
+// parses `{ a, b } = src`, cloning `src` into each of the identifiers.
+fn parse_fan_out( input: ParseStream ) -> parse::Result<Vec<Bind>> {
+    let content;
+    braced!( content in input );
+    let ids = Punctuated::<Ident,Token![,]>::parse_terminated( &content )?;
+    input.parse::<Token![=]>()?;
+    let src = match classify_expr( input.parse::<Expr>()? ) {
+        ExprOrIdent::Ident( src  ) => src,
+        ExprOrIdent::Expr(  expr ) => return Err( parse::Error::new_spanned( expr,
+            "expected an identifier to clone into each of the names in braces" )),
+    };
+    Ok( ids.into_iter().map( |id| Bind::IdId( id, src.clone() )).collect() )
+}
+
+/// Parses a list of `Bind`s separated by commas or semicolons, with an optional trailing separator.
+///
+/// Semicolons are stored as commas in the returned `Punctuated`.
+/// A malformed binding does not stop the parsing of the following ones,
+/// and the errors of all the bindings are returned at once, combined by `syn::Error::combine()`.
+/// `self.{ a, mut b }` clones the fields of `self`, yielding a binding for each field,
+/// e.g. `let a = self.a.clone(); let mut b = self.b.clone();`.
+/// `clone{ a, b }` yields a `Bind::Id` for each identifier, e.g. `let a = a.clone(); let b = b.clone();`.
+/// `{ a, b } = src` yields a `Bind::IdId` for each identifier, e.g. `let a = src.clone(); let b = src.clone();`.
+/// Binding the same name twice is an error, since the latter binding would shadow the former silently,
+/// unless the bindings have `#[cfg(..)]` attributes which may select one of them.
+/// A binding prefixed with `@<int>`, e.g. `@1 a`, is reordered by the integer in ascending order, as the order
+/// of the `let` statements determines the drop order. Bindings without a prefix have the priority of `@0`,
+/// and bindings of the same priority keep their source order.
+///
+/// # Example
+///
+/// ```rust
+/// use bind_syn::{Bind, parse_binds};
+/// use syn::{Expr, Token, bracketed, parse::{Parse, ParseStream}, punctuated::Punctuated};
+///
+/// // parses `[ a, mut b, c = d ] expr`
+/// struct MyInput {
+///     binds : Punctuated<Bind,Token![,]>,
+///     expr  : Expr,
+/// }
+///
+/// impl Parse for MyInput {
+///     fn parse( input: ParseStream ) -> syn::Result<Self> {
+///         let content;
+///         bracketed!( content in input );
+///         let binds = parse_binds( &content )?;
+///         let expr = input.parse()?;
+///         Ok( MyInput{ binds, expr })
+///     }
+/// }
+///
+/// let input = syn::parse_str::<MyInput>( "[ a, mut b, c = d ] a + b + c" ).unwrap();
+/// assert_eq!( input.binds.len(), 3 );
+/// ```
+pub fn parse_binds( input: ParseStream ) -> parse::Result<Punctuated<Bind,Token![,]>> {
+    let mut binds = Punctuated::<Bind,Token![,]>::new();
+    let mut errors = None::<parse::Error>;
+    let mut report = |err: parse::Error| match &mut errors {
+        Some( errors ) => errors.combine( err ),
+        None           => errors = Some( err ),
+    };
+
+    let mut priorities = Vec::<u32>::new();
+
+    while !input.is_empty() {
+        let group = parse_priority( input ).and_then( |priority| {
+            let group = if input.peek( Token![self] ) && input.peek2( Token![.] ) && input.peek3( token::Brace ) {
+                parse_self_fields( input )
+            } else if input.cursor().ident().is_some_and( |( id, _ )| id == "clone" ) && input.peek2( token::Brace ) {
+                parse_clone_group( input )
+            } else if input.peek( token::Brace ) && input.peek2( Token![=] ) {
+                parse_fan_out( input )
+            } else {
+                input.parse::<Bind>().map( |bind| vec![ bind ])
+            };
+            group.map( |group| ( priority.unwrap_or_default(), group ))
+        });
+        match group {
+            Ok(( priority, group )) => for bind in group {
+                if let Some( id ) = local_name( &bind ).filter( |_| !is_cfg_gated( &bind )) {
+                    if binds.iter().any( |bound| !is_cfg_gated( bound ) && local_name( bound ).as_ref() == Some( &id )) {
+                        report( parse::Error::new( id.span(), format!( "binding `{id}` is defined twice" )));
+                        continue;
                     }
-                ExprOrIdent::Ident( id ) =>
-                    return Ok( if immutable {
-                        Bind::Id(    id )
-                    } else {
-                        Bind::MutId( id )
-                    }),
+                }
+                if !binds.empty_or_trailing() {
+                    binds.push_punct( Token![,]( bind.ident().map_or_else( Span::call_site, Ident::span )));
+                }
+                binds.push_value( bind );
+                priorities.push( priority );
+            },
+            // recovers from the malformed binding to report the errors of the following ones too.
+            Err( err ) => {
+                report( err );
+                skip_to_separator( input )?;
+            },
+        }
+        if input.is_empty() {
+            break;
+        }
+        if !input.peek( Token![,] ) && !input.peek( Token![;] ) {
+            report( input.error( "expected `,` or `;`" ));
+            skip_to_separator( input )?;
+            if input.is_empty() {
+                break;
             }
         }
+        let span = match input.parse::<Option<Token![,]>>()? {
+            Some( comma ) => comma.span,
+            None          => input.parse::<Token![;]>()?.span,
+        };
+        if !binds.empty_or_trailing() {
+            binds.push_punct( Token![,]( span ));
+        }
+    }
+
+    // the sort is stable, keeping the source order of the bindings of the same priority.
+    if priorities.iter().any( |priority| *priority != 0 ) {
+        let mut prioritized = priorities.into_iter().zip( binds.into_pairs().map( Pair::into_value )).collect::<Vec<_>>();
+        prioritized.sort_by_key( |( priority, _ )| *priority );
+        binds = prioritized.into_iter().map( |( _, bind )| bind ).collect();
+    }
+
+    match errors {
+        Some( errors ) => Err( errors ),
+        None           => Ok( binds ),
+    }
+}
 
-        panic!( "Invalid input for `bind!()`: {input:?}" );
+// the name of the local defined by the binding, e.g. `_guard` of `keep guard`.
+fn local_name( bind: &Bind ) -> Option<Ident> {
+    match bind {
+        Bind::Keep( id )                                   => Some( kept_ident( None, id )),
+        Bind::Attrs( _, bind ) | Bind::ThenStmt( bind, _ ) => local_name( bind ),
+        bind                                               => bind.ident().cloned(),
     }
 }
 
+// checks whether the binding has a `#[cfg(..)]` attribute, which may select one of several bindings of the same name.
+fn is_cfg_gated( bind: &Bind ) -> bool {
+    match bind {
+        Bind::Attrs( attrs, bind ) => attrs.iter().any( |attr| attr.path().is_ident( "cfg" )) || is_cfg_gated( bind ),
+        Bind::ThenStmt( bind, _ )  => is_cfg_gated( bind ),
+        _                          => false,
+    }
+}
+
+// parses the optional `@<int>` prefix of a binding.
+fn parse_priority( input: ParseStream ) -> parse::Result<Option<u32>> {
+    if !input.peek( Token![@] ) {
+        return Ok( None );
+    }
+    let at = input.parse::<Token![@]>()?;
+    if !input.peek( LitInt ) {
+        return Err( parse::Error::new( at.span, "expected an integer priority after `@`, as in `@1 id`" ));
+    }
+    input.parse::<LitInt>()?.base10_parse().map( Some )
+}
+
+// skips the tokens up to the next `,` or `;` not nested in groups, or to the end of input.
+fn skip_to_separator( input: ParseStream ) -> parse::Result<()> {
+    input.step( |cursor| {
+        let mut rest = *cursor;
+        while let Some(( tt, next )) = rest.token_tree() {
+            if let TokenTree::Punct( punct ) = tt {
+                if matches!( punct.as_char(), ',' | ';' ) {
+                    break;
+                }
+            }
+            rest = next;
+        }
+        Ok(( (), rest ))
+    })
+}
+
 impl ToTokens for Bind {
     fn to_tokens( &self, tokens: &mut proc_macro2::TokenStream ) {
-        tokens.extend( match self {
-            Bind::Id(         id           ) => quote!{ let     #id = #id  .clone(); },
-            Bind::MutId(      id           ) => quote!{ let mut #id = #id  .clone(); },
-            Bind::IdId(       id, id0      ) => quote!{ let     #id = #id0 .clone(); },
-            Bind::MutIdId(    id, id0      ) => quote!{ let mut #id = #id0 .clone(); },
-            Bind::IdExpr(     id,     expr ) => quote!{ let     #id = #expr        ; },
-            Bind::MutIdExpr(  id,     expr ) => quote!{ let mut #id = #expr        ; },
-            Bind::Expr(       id,     expr ) => quote!{ let     #id = #expr        ; },
-            Bind::MutExpr(    id,     expr ) => quote!{ let mut #id = #expr        ; },
-        });
+        self.to_tokens_with_span( Span::call_site(), tokens );
+    }
+}
+
+impl Debug for Bind {
+    fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result {
+        f.debug_tuple( "Bind" )
+            .field( &format_args!( "{}", self.to_token_stream() ))
+            .finish()
+    }
+}
+
+impl PartialEq for Bind {
+    fn eq( &self, other: &Self ) -> bool {
+        self.to_token_stream().to_string() == other.to_token_stream().to_string()
+    }
+}
+
+impl Eq for Bind {}
+
+/// Parses a binding from a string, e.g. `"mut name = expr".parse::<Bind>()`.
+impl FromStr for Bind {
+    type Err = parse::Error;
+
+    fn from_str( s: &str ) -> parse::Result<Self> {
+        syn::parse_str( s )
+    }
+}
+
+/// A list of `Bind`s for generating code programmatically.
+///
+/// `ToTokens` renders the `let` statements of the bindings only.
+///
+/// # Example
+///
+/// ```rust
+/// use bind_syn::{Bind, BindList};
+/// use quote::format_ident;
+///
+/// let mut binds = BindList::new();
+/// binds.push( Bind::clone_of( format_ident!( "a" )));
+/// binds.extend([ "mut b".parse::<Bind>().unwrap() ]);
+///
+/// let block = binds.to_block( syn::parse_quote!( a + b ));
+/// assert_eq!( block.stmts.len(), 3 );
+/// ```
+#[derive( Clone, Debug, Default, PartialEq, Eq )]
+pub struct BindList {
+    binds : Vec<Bind>,
+}
+
+impl BindList {
+    /// Creates an empty list.
+    pub fn new() -> Self { BindList::default() }
+
+    /// Appends a binding to the list.
+    pub fn push( &mut self, bind: Bind ) { self.binds.push( bind ); }
+
+    /// The bindings in the list.
+    pub fn iter( &self ) -> core::slice::Iter<'_, Bind> { self.binds.iter() }
+
+    /// The number of bindings in the list.
+    pub fn len( &self ) -> usize { self.binds.len() }
+
+    /// Returns `true` if the list has no bindings.
+    pub fn is_empty( &self ) -> bool { self.binds.is_empty() }
+
+    /// Builds the block `{ lets..; body }`.
+    pub fn to_block( self, body: Expr ) -> Block {
+        parse_quote!({
+            #self
+            #body
+        })
+    }
+}
+
+impl Extend<Bind> for BindList {
+    fn extend<I: IntoIterator<Item=Bind>>( &mut self, iter: I ) {
+        self.binds.extend( iter );
+    }
+}
+
+impl FromIterator<Bind> for BindList {
+    fn from_iter<I: IntoIterator<Item=Bind>>( iter: I ) -> Self {
+        BindList{ binds: iter.into_iter().collect() }
+    }
+}
+
+impl IntoIterator for BindList {
+    type Item = Bind;
+    type IntoIter = std::vec::IntoIter<Bind>;
+
+    fn into_iter( self ) -> Self::IntoIter { self.binds.into_iter() }
+}
+
+impl ToTokens for BindList {
+    fn to_tokens( &self, tokens: &mut proc_macro2::TokenStream ) {
+        for bind in &self.binds {
+            bind.to_tokens( tokens );
+        }
+    }
+}
+
+/// A list of `Bind`s parsed by `parse_binds()`, for `syn::parse2::<Binds>( tokens )` in other proc macros.
+///
+/// `ToTokens` renders the `let` statements of the bindings, in the order given by their `@<int>` prefixes.
+///
+/// # Example
+///
+/// ```rust
+/// use bind_syn::Binds;
+/// use quote::{ToTokens, quote};
+///
+/// let binds = syn::parse2::<Binds>( quote!{ a, mut b = c, }).unwrap();
+/// assert_eq!( binds.0.len(), 2 );
+/// assert_eq!( binds.to_token_stream().to_string(), quote!{ let a = a.clone(); let mut b = c.clone(); }.to_string() );
+/// ```
+#[derive( Clone, Debug, Default, PartialEq, Eq )]
+pub struct Binds( pub Vec<Bind> );
+
+impl Parse for Binds {
+    fn parse( input: ParseStream ) -> parse::Result<Self> {
+        parse_binds( input ).map( |binds| Binds( binds.into_iter().collect() ))
+    }
+}
+
+impl ToTokens for Binds {
+    fn to_tokens( &self, tokens: &mut proc_macro2::TokenStream ) {
+        for bind in &self.0 {
+            bind.to_tokens( tokens );
+        }
     }
 }
+
+impl From<Binds> for BindList {
+    fn from( binds: Binds ) -> Self { binds.0.into_iter().collect() }
+}
+
+/// The input of `bind!()`, in the form of `borrow { prelude; } ( bindings ) expr`,
+/// the `borrow` keyword and the prelude being optional.
+///
+/// The bindings can be delimited by parentheses, brackets or braces.
+pub struct BindInput {
+    /// the `borrow` keyword, asking non-move closures and async blocks to borrow instead of cloning plain `id` bindings
+    pub borrow    : Option<Ident>,
+    /// the statements placed before the bindings, whose `let` statements must not bind the names of the bindings
+    pub prelude   : Vec<Stmt>,
+    /// the delimiter of the bindings
+    pub delimiter : MacroDelimiter,
+    /// the bindings
+    pub binds     : Punctuated<Bind,Token![,]>,
+    /// the expression using the bindings
+    pub expr      : Expr,
+}
+
+impl Parse for BindInput {
+    fn parse( input: ParseStream ) -> parse::Result<Self> {
+        let borrow = if input.cursor().ident().is_some_and( |( id, rest )| id == "borrow" && rest.any_group().is_some() ) {
+            Some( input.parse::<Ident>()? )
+        } else {
+            None
+        };
+
+        // a leading brace group is the prelude if the bindings and the expression follow it,
+        // or the bindings themselves otherwise.
+        let mut prelude = Vec::new();
+        if input.peek( token::Brace ) && ( input.peek2( token::Paren ) || input.peek2( token::Bracket ) || input.peek2( token::Brace )) {
+            let fork = input.fork();
+            fork.parse::<proc_macro2::TokenTree>()?;
+            fork.parse::<proc_macro2::TokenTree>()?;
+            if !fork.is_empty() {
+                let content;
+                braced!( content in input );
+                prelude = content.call( Block::parse_within )?;
+                if let Some( Stmt::Expr( expr, None )) = prelude.last() {
+                    return Err( parse::Error::new_spanned( expr,
+                        "expected statements in the prelude, add a `;` after the expression" ));
+                }
+            }
+        }
+
+        let content;
+        let delimiter = if input.peek( token::Paren ) {
+            MacroDelimiter::Paren( parenthesized!( content in input ))
+        } else if input.peek( token::Bracket ) {
+            MacroDelimiter::Bracket( bracketed!( content in input ))
+        } else if input.peek( token::Brace ) {
+            MacroDelimiter::Brace( braced!( content in input ))
+        } else {
+            return Err( input.error( "expected parenthesized bindings, in the form of `bind!( ( bindings ) expr )`" ));
+        };
+        let binds = parse_binds( &content )?;
+        check_prelude_shadows( &prelude, &binds )?;
+        if input.is_empty() {
+            return Err( parse::Error::new( delimiter_span( &delimiter ),
+                "expected an expression using the bindings after them, in the form of `bind!( ( bindings ) expr )`" ));
+        }
+        let expr = input.parse::<Expr>()?;
+        if let Expr::Closure( closure ) = unparenthesized( &expr ) {
+            check_shadowed_params( &binds, closure )?;
+        }
+        Ok( BindInput{ borrow, prelude, delimiter, binds, expr })
+    }
+}
+
+// rejects a closure parameter named after a binding, which either shadows the binding or is shadowed by it,
+// depending on where the binding is placed. Destructured parameters are not checked.
+fn check_shadowed_params( binds: &Punctuated<Bind,Token![,]>, closure: &ExprClosure ) -> parse::Result<()> {
+    for param in &closure.inputs {
+        let pat = match param {
+            Pat::Type( PatType{ pat, .. }) => &**pat,
+            pat => pat,
+        };
+        if let Pat::Ident( PatIdent{ ident, subpat: None, .. }) = pat {
+            if binds.iter().any( |bind| bind.ident() == Some( ident )) {
+                return Err( parse::Error::new( ident.span(), format!(
+                    "the closure parameter `{ident}` has the name of a binding, rename either of them" )));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_prelude_shadows( prelude: &[Stmt], binds: &Punctuated<Bind,Token![,]> ) -> parse::Result<()> {
+    for stmt in prelude {
+        if let Stmt::Local( local ) = stmt {
+            if let Some( id ) = binds.iter().filter_map( Bind::ident ).find( |id| pat_binds( &local.pat, id )) {
+                return Err( parse::Error::new( id.span(), format!(
+                    "binding `{id}` is defined twice, in the prelude and in the bindings" )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Strips the parentheses and invisible groups around `expr`, e.g. `move || ..` of `(move || ..)`,
+/// as `bind!()` does before placing the bindings into a closure, an async block or a block.
+pub fn unparenthesized( mut expr: &Expr ) -> &Expr {
+    while let Expr::Paren( ExprParen{ attrs, expr: inner, .. }) | Expr::Group( ExprGroup{ attrs, expr: inner, .. }) = expr {
+        if !attrs.is_empty() {
+            break;
+        }
+        expr = inner;
+    }
+    expr
+}
+
+fn delimiter_span( delimiter: &MacroDelimiter ) -> Span {
+    match delimiter {
+        MacroDelimiter::Paren(   paren   ) => paren.span.join(),
+        MacroDelimiter::Bracket( bracket ) => bracket.span.join(),
+        MacroDelimiter::Brace(   brace   ) => brace.span.join(),
+    }
+}
+
+/// Parses the whole input of `bind!()` into the bindings and the expression using them, without panicking,
+/// for tools reusing the grammar outside of a proc macro.
+///
+/// The input having the `borrow` keyword or a prelude is an error, which `BindInput` supports.
+///
+/// # Example
+///
+/// ```rust
+/// let ( binds, expr ) = bind_syn::try_parse_input( quote::quote!{ ( a, mut b = c ) move || a + b }).unwrap();
+/// assert_eq!( binds.len(), 2 );
+/// assert!( matches!( expr, syn::Expr::Closure(_) ));
+///
+/// assert!( bind_syn::try_parse_input( quote::quote!{ ( a, ) }).is_err() );
+/// ```
+pub fn try_parse_input( tokens: proc_macro2::TokenStream ) -> parse::Result<( Vec<Bind>, Expr )> {
+    let BindInput{ borrow, prelude, binds, expr, .. } = syn::parse2( tokens )?;
+    if let Some( borrow ) = borrow {
+        return Err( parse::Error::new( borrow.span(), "`borrow` is not supported, parse `BindInput` instead" ));
+    }
+    if let Some( stmt ) = prelude.first() {
+        return Err( parse::Error::new_spanned( stmt, "a prelude is not supported, parse `BindInput` instead" ));
+    }
+    Ok(( binds.into_iter().collect(), expr ))
+}
+
+/// Generates `let field = receiver.field.clone();` for each field, as `self.{ .. }` does for the fields of `self`,
+/// for proc macros cloning the handles kept in a struct into closures or tasks.
+///
+/// A receiver other than a path, a field access or a call is parenthesized, e.g. `(*handles).field.clone()`.
+///
+/// # Example
+///
+/// ```rust
+/// use quote::{format_ident, quote};
+///
+/// let fields = [ format_ident!( "db" ), format_ident!( "cache" )];
+/// let lets = bind_syn::clone_fields( &syn::parse_quote!( self.handles ), &fields );
+/// assert_eq!( lets.to_string(), quote!{
+///     let db = self.handles.db.clone();
+///     let cache = self.handles.cache.clone();
+/// }.to_string() );
+/// ```
+pub fn clone_fields( receiver: &Expr, fields: &[Ident] ) -> proc_macro2::TokenStream {
+    let receiver = match receiver {
+        Expr::Path(_) | Expr::Field(_) | Expr::MethodCall(_) | Expr::Call(_) | Expr::Paren(_) => receiver.to_token_stream(),
+        receiver => quote!{ (#receiver) },
+    };
+    fields.iter()
+        .map( |id| Bind::IdExpr( id.clone(), parse_quote_spanned!{ id.span()=> #receiver.#id.clone() }))
+        .collect::<BindList>()
+        .into_token_stream()
+}
+
+/// Re-exports the public items of the crate for `use bind_syn::prelude::*;` in downstream proc macros.
+pub mod prelude {
+    pub use crate::{
+        Bind,
+        BindInput,
+        BindList,
+        Binds,
+        BindingMode,
+        ExprOrIdent,
+        classify_expr,
+        clone_fields,
+        parse_binds,
+        sole_ident,
+        try_parse_input,
+        unparenthesized,
+    };
+}
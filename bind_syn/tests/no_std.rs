@@ -0,0 +1,26 @@
+//! Run with `cargo test-no-std`, i.e. `cargo test -p bind_syn --no-default-features`.
+
+#![cfg( not( feature = "std" ))]
+
+use bind_syn::Bind;
+
+use quote::{ToTokens, quote};
+
+fn tokens( input: &str ) -> String {
+    syn::parse_str::<Bind>( input ).unwrap().to_token_stream().to_string()
+}
+
+#[test]
+fn alloc_paths_without_std() {
+    assert_eq!( tokens( "rc a"      ), quote!{ let a = ::alloc::rc::Rc::clone( &a ); }.to_string()      );
+    assert_eq!( tokens( "rc a = b"  ), quote!{ let a = ::alloc::rc::Rc::clone( &b ); }.to_string()      );
+    assert_eq!( tokens( "arc a"     ), quote!{ let a = ::alloc::sync::Arc::clone( &a ); }.to_string()   );
+    assert_eq!( tokens( "arc a = b" ), quote!{ let a = ::alloc::sync::Arc::clone( &b ); }.to_string()   );
+    assert_eq!( tokens( "weak a"    ), quote!{ let a = ::alloc::rc::Rc::downgrade( &a ); }.to_string()  );
+}
+
+#[test]
+fn dbg_requires_std() {
+    let err = syn::parse_str::<Bind>( "dbg a" ).unwrap_err();
+    assert_eq!( err.to_string(), "`dbg` bindings require the \"std\" feature" );
+}
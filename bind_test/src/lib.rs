@@ -1,11 +1,29 @@
 #[cfg( test )]
 mod tests {
-    use bind::bind;
+    use bind::{bind, bind_clone_all, bind_fn, bindings};
     use std::{
-        cell::Cell,
+        cell::{Cell, RefCell},
+        future::Future,
+        pin::pin,
         rc::Rc,
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        task::{Context, Poll, Waker},
+        thread,
     };
 
+    fn block_on<F: Future>( fut: F ) -> F::Output {
+        let mut fut = pin!( fut );
+        let mut cx = Context::from_waker( Waker::noop() );
+        loop {
+            if let Poll::Ready( output ) = fut.as_mut().poll( &mut cx ) {
+                return output;
+            }
+        }
+    }
+
     #[test]
     fn closure_works() {
         let i           = Rc::new( Cell::new(1) );
@@ -75,4 +93,1788 @@ mod tests {
         assert_eq!( id_id.get(), 30 );
         assert_eq!( mut_id_id, "4" );
     }
+
+    #[test]
+    fn ref_works() {
+        let foo = String::from("foo");
+        let baz = vec![ 1, 2, 3 ];
+
+        let f = bind!( ( ref foo, ref bar = baz )
+            || {
+                let _: &String = foo;
+                let _: &Vec<i32> = bar;
+                foo.len() + bar.len()
+            }
+        );
+        assert_eq!( f(), 6 );
+        assert_eq!( foo, "foo" );
+    }
+
+    #[test]
+    fn ref_mut_works() {
+        let mut count = 0;
+        let mut total = vec![];
+        {
+            let mut f = bind!( ( ref mut count, ref mut acc = total )
+                || {
+                    *count += 1;
+                    acc.push( *count );
+                }
+            );
+            f();
+            f();
+        }
+        assert_eq!( count, 2 );
+        assert_eq!( total, vec![ 1, 2 ]);
+    }
+
+    #[test]
+    fn rc_works() {
+        let foo = Rc::new( Cell::new(1) );
+        let baz = Rc::new( Cell::new(2) );
+        let rc = Rc::new(3);
+
+        bind!( ( rc foo, rc bar = baz, rc )
+            move || {
+                foo.set( foo.get() + *rc );
+                bar.set( bar.get() + *rc );
+            }
+        )();
+        assert_eq!( foo.get(), 4 );
+        assert_eq!( baz.get(), 5 );
+        assert_eq!( Rc::strong_count( &foo ), 1 );
+    }
+
+    #[test]
+    fn arc_works() {
+        let foo = Arc::new( AtomicUsize::new(1) );
+        let baz = Arc::new( AtomicUsize::new(2) );
+
+        thread::spawn( bind!( ( arc foo, arc bar = baz )
+            move || {
+                foo.fetch_add( 10, Ordering::SeqCst );
+                bar.fetch_add( 10, Ordering::SeqCst );
+            }
+        )).join().unwrap();
+        assert_eq!( foo.load( Ordering::SeqCst ), 11 );
+        assert_eq!( baz.load( Ordering::SeqCst ), 12 );
+        assert_eq!( Arc::strong_count( &foo ), 1 );
+    }
+
+    #[test]
+    fn weak_works() {
+        let foo = Rc::new( Cell::new(1) );
+        let node = Rc::new( Cell::new(2) );
+
+        let f = bind!( ( weak foo, weak cb = node )
+            move || ( foo.upgrade().map( |foo| foo.get() ), cb.upgrade().map( |cb| cb.get() ))
+        );
+        assert_eq!( f(), ( Some(1), Some(2) ));
+        assert_eq!( Rc::strong_count( &foo ), 1 );
+
+        drop( foo );
+        drop( node );
+        assert_eq!( f(), ( None, None ));
+    }
+
+    #[test]
+    fn move_works() {
+        struct NotClone( i32 );
+
+        let foo = NotClone(1);
+        let original = NotClone(2);
+
+        let f = bind!( ( move foo, move owned = original )
+            move || foo.0 + owned.0
+        );
+        assert_eq!( f(), 3 );
+    }
+
+    #[test]
+    fn into_works() {
+        let foo = 1_u8;
+
+        let f = bind!( ( into foo, into s = "x" )
+            move || -> ( u32, String ) { ( foo, s ) }
+        );
+        assert_eq!( f(), ( 1, String::from("x") ));
+    }
+
+    #[test]
+    fn to_string_works() {
+        let msg = "hello";
+        let n = 42;
+        let string = String::from("plain");
+
+        let f = bind!( ( string msg, string label = n + 1, string )
+            move || {
+                let _: ( &String, &String ) = ( &msg, &label );
+                format!( "{msg} {label} {string}" )
+            }
+        );
+        assert_eq!( f(), "hello 43 plain" );
+    }
+
+    #[test]
+    fn copy_works() {
+        let value = 7;
+        let foo: &i32 = &value;
+        let ptr: &i32 = &value;
+
+        let f = bind!( ( copy foo, copy n = ptr )
+            move || {
+                let _: ( i32, i32 ) = ( foo, n );
+                foo + n
+            }
+        );
+        assert_eq!( f(), 14 );
+        assert_eq!( *foo, 7 );
+    }
+
+    #[test]
+    fn type_annotation_works() {
+        let iter = [ "a", "b" ].into_iter().map( String::from );
+        let n = 3_u8;
+
+        let f = bind!( ( v: Vec<String> = iter.collect(), mut w: Vec<u8> = Vec::new(), into s: String = "x", into n: u32 )
+            move || {
+                w.push( 1 );
+                ( v.len(), w, s + "y", n )
+            }
+        );
+        assert_eq!( f(), ( 2, vec![1], String::from("xy"), 3 ));
+    }
+
+    #[test]
+    fn field_auto_naming_works() {
+        struct Config { name: String, db: Db }
+        struct Db { url: String }
+
+        let config = Config{ name: String::from("app"), db: Db{ url: String::from("db://") }};
+
+        let f = bind!( ( config.name.clone(), config.db.url.to_owned() )
+            move || format!( "{name} {url}" )
+        );
+        assert_eq!( f(), "app db://" );
+    }
+
+    #[test]
+    fn tuple_destructuring_works() {
+        let pair = ( 1, String::from("one") );
+        let nested = (( 2, 3 ), 4 );
+
+        let mut f = bind!( ( (a, b) = pair.clone(), mut ((c, d), e) = nested )
+            move || {
+                d += 1;
+                e += 1;
+                c += d + e;
+                format!( "{a} {b} {c}" )
+            }
+        );
+        assert_eq!( f(), "1 one 11" );
+        assert_eq!( pair, ( 1, String::from("one") ));
+    }
+
+    #[test]
+    fn struct_destructuring_works() {
+        #[derive( Clone )]
+        struct Point { x: i32, y: i32, z: i32 }
+
+        let p = Point{ x: 1, y: 2, z: 3 };
+
+        let f = bind!( ( Point{ x, y, .. } = p.clone(), Point{ z: depth, .. } = p.clone() )
+            move || x + y + depth
+        );
+        assert_eq!( f(), 6 );
+        assert_eq!( p.x, 1 );
+    }
+
+    #[test]
+    fn at_pattern_works() {
+        fn unwrap_or_zero( opt: Option<i32> ) -> i32 {
+            bind!( ( some @ Some(_) = opt else { return 0 } ) some.unwrap() )
+        }
+
+        let f = bind!( ( pair @ ( a, b ) = ( 1, 2 )) move || pair.0 + pair.1 + a + b );
+        assert_eq!( f(), 6 );
+        assert_eq!( unwrap_or_zero( Some( 3 )), 3 );
+        assert_eq!( unwrap_or_zero( None ), 0 );
+    }
+
+    #[test]
+    fn call_site_bindings_are_visible() {
+        use bind_test_macros::call_site_bindings;
+
+        let src = vec![ 1, 2 ];
+        call_site_bindings!( mut v = src, (x, y) = ( 3, 4 ), n: usize = 5 );
+        v.push( x + y );
+        assert_eq!( v, [ 1, 2, 7 ]);
+        assert_eq!( n, 5 );
+        assert_eq!( src, [ 1, 2 ]);
+    }
+
+    // the notes of the diagnostics differ with the "hygienic" feature.
+    #[cfg( not( feature = "hygienic" ))]
+    #[test]
+    fn ui() {
+        trybuild::TestCases::new().compile_fail( "ui/*.rs" );
+    }
+
+    // the diagnostics of the generated code with `Span::mixed_site()` hygiene.
+    #[cfg( feature = "hygienic" )]
+    #[test]
+    fn ui_hygienic() {
+        trybuild::TestCases::new().compile_fail( "ui_hygienic/*.rs" );
+    }
+
+    #[test]
+    fn raw_ident_works() {
+        let r#type = Rc::new( Cell::new(1) );
+        let r#fn = String::from("fn");
+
+        bind!( ( r#type, mut r#async = r#fn, r#fn = r#type )
+            move || {
+                r#type.set( 2 );
+                r#async.push( '!' );
+                assert_eq!( r#async, "fn!" );
+                assert_eq!( r#fn.get(), 2 );
+            }
+        )();
+        assert_eq!( r#type.get(), 2 );
+        assert_eq!( r#fn, "fn" );
+    }
+
+    #[test]
+    fn async_closure_works() {
+        let foo = Rc::new( Cell::new(1) );
+        let s = String::from("s");
+
+        let f = bind!( ( foo, mut s ) async move || {
+            s.push( '!' );
+            foo.set( foo.get() + 1 );
+            s
+        });
+        assert_eq!( block_on( f() ), "s!" );
+        assert_eq!( foo.get(), 2 );
+        assert_eq!( s, "s" );
+
+        let g = bind!( ( foo, s ) async || {
+            foo.set( foo.get() + 1 );
+            s
+        });
+        assert_eq!( block_on( g() ), "s" );
+        assert_eq!( foo.get(), 3 );
+    }
+
+    #[test]
+    fn async_block_works() {
+        fn assert_static<F: Future + 'static>( fut: F ) -> F { fut }
+
+        let foo = Rc::new( Cell::new(1) );
+        let s = String::from("s");
+
+        let fut = assert_static( bind!( ( foo, mut s ) async move {
+            s.push( '!' );
+            foo.set( foo.get() + 1 );
+            s
+        }));
+        assert_eq!( block_on( fut ), "s!" );
+        assert_eq!( foo.get(), 2 );
+
+        let fut = bind!( ( foo, s ) async {
+            foo.set( foo.get() + 1 );
+            s
+        });
+        assert_eq!( block_on( fut ), "s" );
+        assert_eq!( foo.get(), 3 );
+    }
+
+    #[test]
+    fn bind_fn_works() {
+        #[bind_fn( foo, mut bar.to_owned(), baz = qux.to_owned() )]
+        #[inline]
+        fn make_callback( foo: &Rc<Cell<i32>>, bar: &str, qux: &str ) -> impl FnMut() -> String {
+            move || {
+                foo.set( foo.get() + 1 );
+                bar.push( '!' );
+                format!( "{bar}{baz}" )
+            }
+        }
+
+        let foo = Rc::new( Cell::new(1) );
+        let bar = String::from("bar");
+        let mut f = make_callback( &foo, &bar, "baz" );
+        assert_eq!( f(), "bar!baz" );
+        assert_eq!( f(), "bar!!baz" );
+        assert_eq!( foo.get(), 3 );
+        assert_eq!( bar, "bar" );
+    }
+
+    #[test]
+    fn bindings_works() {
+        let foo = Rc::new( Cell::new(1) );
+        let baz = String::from("baz");
+
+        bindings!( foo, mut bar = baz );
+        bar.push( '!' );
+        foo.set( 2 );
+
+        assert_eq!( bar, "baz!" );
+        assert_eq!( baz, "baz" );
+        assert_eq!( Rc::strong_count( &foo ), 2 );
+    }
+
+    #[test]
+    fn semicolon_separators_work() {
+        let a = Rc::new( Cell::new(1) );
+        let d = String::from("d");
+
+        bind!( ( a; mut c = d; )
+            move || {
+                a.set( 2 );
+                c.push( '!' );
+                assert_eq!( c, "d!" );
+            }
+        )();
+        assert_eq!( a.get(), 2 );
+    }
+
+    #[test]
+    fn block_works() {
+        let foo = Rc::new( Cell::new(1) );
+        let bar = String::from("bar");
+
+        let len = bind!( ( foo, mut bar ) {
+            foo.set( 2 );
+            bar.push( '!' );
+            let len = bar.len();
+            len + Rc::strong_count( &foo )
+        });
+        assert_eq!( len, 4 + 2 );
+        assert_eq!( foo.get(), 2 );
+        assert_eq!( bar, "bar" );
+    }
+
+    #[test]
+    fn try_works() {
+        use std::num::ParseIntError;
+
+        let s = "21";
+        let f = bind!( ( try n = s.parse::<i32>(), m = s.parse::<i32>()? )
+            || -> Result<i32, ParseIntError> { Ok( n + m ) }
+        );
+        assert_eq!( f(), Ok( 42 ));
+
+        let s = "x";
+        let f = bind!( ( try n = s.parse::<i32>() )
+            || -> Result<i32, ParseIntError> { Ok( n ) }
+        );
+        assert!( f().is_err() );
+    }
+
+    #[test]
+    fn let_else_works() {
+        let opt = Some(21);
+        let f = bind!( ( Some(x) = opt else { return 0 })
+            || x * 2
+        );
+        assert_eq!( f(), 42 );
+
+        let mut sum = 0;
+        for opt in [ Some(1), None, Some(3) ] {
+            bind!( ( Some(x) = opt else { continue }) {
+                sum += x;
+            });
+        }
+        assert_eq!( sum, 4 );
+    }
+
+    #[test]
+    fn qclone_works() {
+        #[derive( Clone, Debug, PartialEq )]
+        struct Foo( i32 );
+
+        impl Foo {
+            #[allow( clippy::should_implement_trait )]
+            fn clone( &self ) -> i32 { self.0 }
+        }
+
+        let foo = Foo(1);
+        let bar = Foo(2);
+
+        let f = bind!( ( qclone foo, qclone baz = bar )
+            move || ( foo, baz )
+        );
+        assert_eq!( f(), ( Foo(1), Foo(2) ));
+
+        let g = bind!( ( foo ) move || foo );
+        assert_eq!( g(), 1 );
+    }
+
+    #[test]
+    fn deref_clone_works() {
+        let s = String::from("foo");
+        let foo: &String = &s;
+
+        let f = bind!( ( clone *foo ) move || {
+            let _: &String = &foo;
+            foo + "!"
+        });
+        assert_eq!( f(), "foo!" );
+        assert_eq!( foo, "foo" );
+    }
+
+    #[test]
+    fn attributes_work() {
+        let foo = String::from("foo");
+        let f = bind!( ( #[cfg(test)] bar = foo, #[cfg(not(test))] bar = 42 ) move || bar );
+        assert_eq!( f(), "foo" );
+    }
+
+    #[test]
+    fn nested_bind_works() {
+        let foo = String::from("foo");
+        let f = bind!( (foo) move || {
+            let g = bind!( (bar = foo) move || bar.len() );
+            ( g(), foo )
+        });
+        assert_eq!( f(), ( 3, String::from("foo") ));
+    }
+
+    #[test]
+    fn mut_rename_then_clone_works() {
+        let b = String::from("b");
+        let c = String::from("c");
+        let f = bind!( (mut a = b, c) move || { a.push_str( &c ); a });
+        assert_eq!( f(), "bc" );
+    }
+
+    #[test]
+    fn await_works() {
+        async fn fetch( s: &str ) -> String { s.to_owned() }
+
+        let f = async || {
+            let data = bind!( (await data = fetch( "data" )) async move { data.len() });
+            data.await
+        };
+        assert_eq!( block_on( f() ), 4 );
+    }
+
+    #[test]
+    fn delimiters_work() {
+        let foo = String::from("foo");
+        let f = bind!( ( bar = foo ) move || bar );
+        let g = bind![ [ bar = foo ] move || bar ];
+        let h = bind!{ { bar = foo } move || bar };
+        assert_eq!( ( f(), g(), h() ), ( foo.clone(), foo.clone(), foo ));
+    }
+
+    #[test]
+    fn bind_clone_all_works() {
+        let foo = Rc::new( Cell::new(1) );
+        let bar = String::from("bar");
+        let f = bind_clone_all!( foo, mut bar, => move || {
+            foo.set( foo.get() + 1 );
+            bar.push( '!' );
+            bar
+        });
+        assert_eq!( f(), "bar!" );
+        assert_eq!( ( foo.get(), bar ), ( 2, String::from("bar") ));
+    }
+
+    #[test]
+    fn self_fields_work() {
+        struct Foo {
+            name  : String,
+            count : Rc<Cell<u32>>,
+        }
+
+        impl Foo {
+            fn callback( &self ) -> impl FnMut() -> String + use<> {
+                bind!( (self.{ count, mut name }) move || {
+                    count.set( count.get() + 1 );
+                    name.push( '!' );
+                    name.clone()
+                })
+            }
+        }
+
+        let foo = Foo{ name: String::from("foo"), count: Rc::new( Cell::new(0) )};
+        let mut f = foo.callback();
+        assert_eq!( f(), "foo!" );
+        assert_eq!( f(), "foo!!" );
+        assert_eq!( ( foo.name.as_str(), foo.count.get() ), ( "foo", 2 ));
+    }
+
+    #[test]
+    fn take_works() {
+        let other = String::from("other");
+        let f = bind!( (take s = other) move || s );
+        assert_eq!( f(), "other" );
+    }
+
+    #[test]
+    fn typed_closure_works() {
+        let prefix = String::from("#");
+        let f = bind!( (prefix) move |x: i32| -> String { format!( "{prefix}{x}" )});
+        let g = bind!( (prefix) |x: i32| -> String { format!( "{prefix}{x}" )});
+        assert_eq!( ( f(1), g(2) ), ( String::from("#1"), String::from("#2") ));
+    }
+
+    #[test]
+    fn prelude_works() {
+        let name = std::sync::Mutex::new( String::from("foo") );
+        let f = bind!( { let guard = name.lock().unwrap(); } ( s = guard.to_uppercase() ) move || s );
+        let g = bind!( { let n = 2; } ( s = name.lock().unwrap().repeat( n )) || s.clone() );
+        assert_eq!( ( f(), g() ), ( String::from("FOO"), String::from("foofoo") ));
+
+        let h = bind!( { let n = 3; } [ m = n + 1 ] m );
+        assert_eq!( h, 4 );
+    }
+
+    #[test]
+    fn index_with_name_works() {
+        let v = [ String::from("a"), String::from("b") ];
+        let f = bind!( (first = v[0].clone()) move || first );
+        assert_eq!( ( f(), v.len() ), ( String::from("a"), 2 ));
+    }
+
+    #[test]
+    fn higher_ranked_closure_works() {
+        fn call_with<F: for<'a> Fn( &'a str ) -> usize>( f: F ) -> usize { f( "abc" ) }
+
+        // explicit `for<'a>` binders are unstable, see ui/closure_binder.rs for their placement.
+        let prefix = String::from("#");
+        let f = bind!( (prefix) move |x: &str| -> usize { prefix.len() + x.len() });
+        let g = bind!( (prefix) |x: &str| -> usize { prefix.len() + x.len() });
+        assert_eq!( ( call_with( f ), call_with( g )), ( 4, 4 ));
+    }
+
+    #[test]
+    fn cast_works() {
+        let count = 300_u32;
+        let small = -1_i8;
+        let f = bind!( (cast n: usize = count + 1, cast byte: u8 = count, cast small: u16) move || ( n, byte, small ));
+        assert_eq!( f(), ( 301, 44, 65535 ));
+    }
+
+    #[test]
+    fn borrow_mode_works() {
+        struct Counted( Rc<Cell<u32>> );
+
+        impl Clone for Counted {
+            fn clone( &self ) -> Self {
+                self.0.set( self.0.get() + 1 );
+                Counted( self.0.clone() )
+            }
+        }
+
+        let clones = Rc::new( Cell::new(0) );
+        let foo = Counted( clones.clone() );
+        let bar = String::from("bar");
+
+        let f = bind!( borrow ( foo, n = bar.len() ) || foo.0.get() + n as u32 );
+        assert_eq!( ( f(), f() ), ( 3, 3 ));
+        assert_eq!( clones.get(), 0 );
+
+        let g = bind!( ( foo ) || foo.0.get() );
+        assert_eq!( g(), 1 );
+
+        let h = bind!( borrow ( foo ) move || foo.0.get() );
+        assert_eq!( h(), 2 );
+    }
+
+    #[test]
+    fn pin_works() {
+        async fn fetch( s: &str ) -> String { s.to_owned() }
+
+        let poll = bind!( (pin fut = fetch( "data" )) {
+            let mut cx = Context::from_waker( Waker::noop() );
+            fut.as_mut().poll( &mut cx ).map( |data| data.len() )
+        });
+        assert_eq!( poll, Poll::Ready(4) );
+
+        let fut = fetch( "data" );
+        assert_eq!( bind!( (pin fut) block_on( fut )), "data" );
+    }
+
+    #[test]
+    fn struct_update_works() {
+        #[derive( Clone, Debug, PartialEq )]
+        struct Config{ timeout: u32, retries: u32 }
+
+        let base = Config{ timeout: 1, retries: 2 };
+        let timeout = 3;
+        let f = bind!( (cfg = Config{ timeout, ..base.clone() }) move || cfg );
+        assert_eq!( f(), Config{ timeout: 3, retries: 2 });
+        assert_eq!( base.timeout, 1 );
+    }
+
+    #[test]
+    fn dbg_works() {
+        let foo = String::from("foo");
+        let f = bind!( (dbg foo, dbg n = foo.len()) move || ( foo, n ));
+        assert_eq!( f(), ( String::from("foo"), 3 ));
+    }
+
+    #[test]
+    fn keep_works() {
+        let guard = Rc::new(());
+        let r#type = Rc::new(());
+        let f = bind!( (keep guard, keep r#type) move || () );
+        assert_eq!( Rc::strong_count( &guard ), 2 );
+        assert_eq!( Rc::strong_count( &r#type ), 2 );
+        f();
+        drop( f );
+        assert_eq!( Rc::strong_count( &guard ), 1 );
+    }
+
+    #[test]
+    fn own_works() {
+        let s = "foo";
+        let v: &[i32] = &[ 1, 2, 3 ];
+        let f = bind!( (own s, own w = v) move || ( s, w ));
+        let ( s, w ): ( String, Vec<i32> ) = f();
+        assert_eq!( s, "foo" );
+        assert_eq!( w, [ 1, 2, 3 ]);
+    }
+
+    #[test]
+    fn clone_group_works() {
+        let a = String::from("a");
+        let b = String::from("b");
+        let c = 3;
+        let f = bind!( (clone{ a, b }, n = c + 1) move || format!( "{a}{b}{n}" ));
+        assert_eq!( f(), "ab4" );
+        assert_eq!( a + &b, "ab" );
+    }
+
+    // the generated clones carry the spans of the cloned identifiers, for `clippy::redundant_clone` to find
+    // clones of values never used afterwards, which the "hygienic" feature hides from it.
+    #[test]
+    #[cfg_attr( not( feature = "hygienic" ), expect( clippy::redundant_clone ))]
+    fn redundant_clone_is_linted() {
+        let name = String::from("name");
+        let f = bind!( (other = name) move || other.len() );
+        assert_eq!( f(), 4 );
+    }
+
+    #[test]
+    fn fan_out_works() {
+        let src = Rc::new( Cell::new(1) );
+        let f = bind!( ({ a, b } = src) move || {
+            a.set( 2 );
+            ( Rc::ptr_eq( &a, &b ), b.get() )
+        });
+        assert_eq!( f(), ( true, 2 ));
+        assert_eq!( Rc::strong_count( &src ), 3 );
+
+        let s = String::from("s");
+        let g = bind!( ({ x, y } = s) move || {
+            let mut y = y;
+            y.push('!');
+            x + &y
+        });
+        assert_eq!( g(), "ss!" );
+        assert_eq!( s, "s" );
+    }
+
+    #[test]
+    fn if_and_match_work() {
+        let a = String::from("a");
+        let b = String::from("b");
+        let cond = false;
+        let f = bind!( (x = if cond { a.clone() } else { b.clone() }, n = match x.len() { 0 => 0, n => n + 1 }) move || ( x, n ));
+        assert_eq!( f(), ( String::from("b"), 2 ));
+    }
+
+    #[test]
+    fn rc_rename_keeps_source() {
+        let shared = Rc::new( Cell::new(1) );
+        let f = bind!( (rc handle = shared) move || handle.set( handle.get() + 1 ));
+        assert_eq!( Rc::strong_count( &shared ), 2 );
+        f();
+        assert_eq!( shared.get(), 2 );
+    }
+
+    #[test]
+    fn borrow_mut_works() {
+        let cell = RefCell::new( vec![ 1 ]);
+        let push = bind!( (borrow_mut v = cell) |n: i32| v.push( n ));
+        push( 2 );
+        push( 3 );
+        assert_eq!( *cell.borrow(), [ 1, 2, 3 ]);
+
+        let total = bind!( (borrow_mut cell) || { cell.push( 4 ); cell.iter().sum::<i32>() });
+        assert_eq!( total(), 10 );
+    }
+
+    #[test]
+    #[cfg_attr( not( feature = "hygienic" ), expect( clippy::clone_on_copy ))]
+    fn clone_on_copy_is_linted() {
+        let n = 1;
+        assert_eq!( bind!( (n) move || n )(), 1 );
+    }
+
+    #[test]
+    #[deny( clippy::clone_on_copy )]
+    fn copyok_works() {
+        let n = 1;
+        let m = 2;
+        assert_eq!( bind!( (copyok n, copyok mut k = m) move || { k += n; k })(), 3 );
+    }
+
+    #[test]
+    fn boxed_closures_work() {
+        let foo = String::from("foo");
+
+        let f: Box<dyn Fn() -> usize> = Box::new( bind!( (foo) move || foo.len() ));
+        assert_eq!( f(), 3 );
+
+        let mut g: Box<dyn FnMut() -> usize> = Box::new( bind!( (mut s = foo) move || { s.push('!'); s.len() }));
+        assert_eq!( g(), 4 );
+        assert_eq!( g(), 5 );
+
+        let h: Box<dyn Fn( &str ) -> usize> = Box::new( bind!( (foo) move |s| foo.len() + s.len() ));
+        assert_eq!( h( "ab" ), 5 );
+
+        let k: Box<dyn Fn( &str ) -> String> = Box::new( bind!( (foo) |s| foo + s ));
+        assert_eq!( k( "d" ), "food" );
+
+        let mut handlers: Vec<Box<dyn Fn() -> String>> = Vec::new();
+        handlers.push( Box::new( bind!( (foo) move || foo.clone() )));
+        handlers.push( Box::new( bind!( (foo) move || foo.to_uppercase() )));
+        assert_eq!( handlers.iter().map( |f| f() ).collect::<String>(), "fooFOO" );
+    }
+
+    #[test]
+    fn priority_works() {
+        struct Guard<'a>( &'a RefCell<Vec<u8>>, u8 );
+        impl Drop for Guard<'_> {
+            fn drop( &mut self ) { self.0.borrow_mut().push( self.1 ); }
+        }
+
+        let log = RefCell::new( Vec::new() );
+        bind!( (@2 _a = Guard( &log, 1 ), @1 _b = Guard( &log, 2 ), _c = Guard( &log, 3 )) {} );
+        assert_eq!( *log.borrow(), [ 1, 2, 3 ]);
+
+        log.borrow_mut().clear();
+        bind!( (@1 _a = Guard( &log, 1 ), @1 _b = Guard( &log, 2 ), _c = Guard( &log, 3 )) {} );
+        assert_eq!( *log.borrow(), [ 2, 1, 3 ]);
+
+        let ( a, b ) = ( String::from("a"), String::from("b") );
+        assert_eq!( bind!( (@1 a, @0 b) move || a + &b )(), "ab" );
+    }
+
+    // the closures are emitted unchanged, to be called in place.
+    #[test]
+    #[expect( clippy::redundant_closure_call )]
+    fn empty_bindings_work() {
+        assert_eq!( bind!( () move || 5 )(), 5 );
+
+        let f: fn() -> i32 = bind!( () || 6 );
+        assert_eq!( f(), 6 );
+
+        // the place expression is not moved into a block.
+        let mut v = vec![ 1 ];
+        bind!( () v ).push( 2 );
+        bind!( [] v[0] ) = 0;
+        assert_eq!( v, [ 0, 2 ]);
+
+        let foo = String::from("foo");
+        assert_eq!( bind!( borrow (foo) || foo.len() )(), 3 );
+    }
+
+    #[test]
+    fn named_slice_range_works() {
+        let v = [ 1, 2, 3, 4 ];
+        let n = 2;
+        let f = bind!( (head = v[..n].to_vec(), mid = v[1..3].to_vec(), range = 0..n) move || ( head, mid, range.len() ));
+        assert_eq!( f(), ( vec![ 1, 2 ], vec![ 2, 3 ], 2 ));
+    }
+
+    #[test]
+    fn then_works() {
+        let src = vec![ 1, 2 ];
+        let f = bind!( (mut v = src then v.reserve( 10 ) then v.push( 3 ), n = 0 then assert_eq!( n, 0 )) move || ( v.capacity() >= 12, v, n ));
+        assert_eq!( f(), ( true, vec![ 1, 2, 3 ], 0 ));
+
+        let log = RefCell::new( String::new() );
+        let g = bind!( (#[cfg( test )] s = String::from("x") then log.borrow_mut().push_str( &s )) || s.len() );
+        assert_eq!( ( g(), g(), log.borrow().as_str() ), ( 1, 1, "xx" ));
+
+        #[bind_fn( mut s.to_owned() then s.push( '!' ))]
+        fn shout( s: &str ) -> String { s }
+        assert_eq!( shout( "hi" ), "hi!" );
+    }
+
+    #[test]
+    fn parenthesized_closure_works() {
+        let foo = String::from("foo");
+        let f = bind!( (foo) (move || foo.len()) );
+        let g = bind!( (foo) ((|| foo)) );
+        let h = bind!( borrow (foo) (|| foo.len()) );
+        assert_eq!( ( f(), g(), h() ), ( 3, String::from("foo"), 3 ));
+        assert_eq!( foo, "foo" );
+    }
+
+    #[test]
+    fn later_bindings_see_earlier_ones() {
+        let ( a, b ) = ( String::from("a"), String::from("b") );
+
+        let f = bind!( (a = b, c = a) move || a + &c );
+        assert_eq!( f(), "bb" );
+
+        let g = bind!( (a = b, b = a) move || a + &b );
+        assert_eq!( g(), "bb" );
+
+        let h = bind!( (a, x = a.clone() + "x", y = x + "y") move || ( a, y ));
+        assert_eq!( h(), ( String::from("a"), String::from("axy") ));
+    }
+}
+
+#[cfg( test )]
+mod syn_tests {
+    use bind_syn::{Bind, parse_binds};
+    use quote::{ToTokens, format_ident, quote};
+    use syn::{parse::Parser, parse_quote};
+
+    fn parse_err( input: &str ) -> String {
+        match syn::parse_str::<Bind>( input ) {
+            Ok(  _   ) => panic!( "`{input}` should not parse" ),
+            Err( err ) => err.to_string(),
+        }
+    }
+
+    #[test]
+    fn invalid_input_is_error() {
+        assert_eq!( parse_err( "1 + 2" ),
+            "cannot infer a binding name from this expression, give an explicit name with `name = expr`" );
+        assert_eq!( parse_err( "a.b = c" ), "expected an identifier on the left of `=`" );
+        for input in [ "HashMap::new()", "Arc::new(x)", "Vec::<u8>::new().len()", "Self::default()" ] {
+            assert_eq!( parse_err( input ),
+                "cannot infer a binding name from an associated function call, give an explicit name with `name = expr`" );
+        }
+        assert_eq!( syn::parse_str::<Bind>( "name = Arc::new(x)" ).unwrap().ident().unwrap(), "name" );
+        for input in [ "a + b", "f(x, y)", "(a + b).to_string()" ] {
+            assert!( parse_err( input ).starts_with( "cannot infer a binding name from an expression referring to both" ), "{input}" );
+        }
+        for ( input, ident ) in [ ( "a + a", "a" ), ( "f(x, 1)", "x" ), ( "x * 2 + 1", "x" ), ( "f(x, x.len())", "x" )] {
+            assert_eq!( syn::parse_str::<Bind>( input ).unwrap().ident().unwrap(), ident, "{input}" );
+        }
+        for input in [ "v[0]", "v[0].clone()", "v[i].to_owned()", "v[..n]", "v[1..3]", "v[..n].to_vec()" ] {
+            assert_eq!( parse_err( input ),
+                "cannot infer a binding name from an index expression, give an explicit name with `name = expr`" );
+        }
+        assert_eq!( syn::parse_str::<Bind>( "first = v[0]" ).unwrap().ident().unwrap(), "first" );
+        assert_eq!( syn::parse_str::<Bind>( "head = v[..n].to_vec()" ).unwrap().ident().unwrap(), "head" );
+        for input in [ "0..n", "a..=b", "..n" ] {
+            assert_eq!( parse_err( input ),
+                "cannot infer a binding name from a range expression, give an explicit name with `name = expr`" );
+        }
+        for input in [ "Config{ timeout }", "Config{ timeout, ..base.clone() }", "Config{ ..base }.validate()" ] {
+            assert_eq!( parse_err( input ),
+                "cannot infer a binding name from a struct literal, give an explicit name with `name = expr`" );
+        }
+        assert_eq!( syn::parse_str::<Bind>( "cfg = Config{ timeout, ..base }" ).unwrap().ident().unwrap(), "cfg" );
+        for input in [ "vec![1, 2, 3]", "format!( \"{x}\" )", "format!( \"{}\", x ).len()" ] {
+            assert_eq!( parse_err( input ),
+                "cannot infer a binding name from a macro call, give an explicit name with `name = expr`" );
+        }
+        assert_eq!( syn::parse_str::<Bind>( "v = vec![1, 2, 3]" ).unwrap().ident().unwrap(), "v" );
+        for input in [ "if cond { a.clone() } else { b.clone() }", "match opt { Some(a) => a, None => b }" ] {
+            assert_eq!( parse_err( input ),
+                "cannot infer a binding name from an `if` or `match` expression, give an explicit name with `name = expr`" );
+        }
+        assert_eq!( parse_err( "self.to_owned()" ),
+            "cannot bind to `self`; give an explicit name with `name = expr`" );
+    }
+
+    #[test]
+    fn accessors_work() {
+        for ( input, ident, is_mut ) in [
+            ( "a"                 , "a", false ),
+            ( "mut a"             , "a", true  ),
+            ( "a = b"             , "a", false ),
+            ( "mut a = b"         , "a", true  ),
+            ( "a = b.c()"         , "a", false ),
+            ( "mut a = b.c()"     , "a", true  ),
+            ( "s.to_owned()"      , "s", false ),
+            ( "mut s.to_owned()"  , "s", true  ),
+            ( "ref a"             , "a", false ),
+            ( "ref a = b"         , "a", false ),
+            ( "ref mut a"         , "a", false ),
+            ( "ref mut a = b"     , "a", false ),
+            ( "rc a"              , "a", false ),
+            ( "rc a = b"          , "a", false ),
+            ( "arc a"             , "a", false ),
+            ( "arc a = b"         , "a", false ),
+            ( "weak a"            , "a", false ),
+            ( "weak a = b"        , "a", false ),
+            ( "move a"            , "a", false ),
+            ( "move a = b"        , "a", false ),
+            ( "into a"            , "a", false ),
+            ( "into a = b"        , "a", false ),
+            ( "string a"          , "a", false ),
+            ( "string a = b"      , "a", false ),
+            ( "a: T = b"          , "a", false ),
+            ( "mut a: T = b"      , "a", true  ),
+            ( "copy a"            , "a", false ),
+            ( "copy a = b"        , "a", false ),
+            ( "try a = b"         , "a", false ),
+            ( "qclone a"          , "a", false ),
+            ( "qclone a = b"      , "a", false ),
+        ] {
+            let bind = syn::parse_str::<Bind>( input ).unwrap();
+            assert_eq!( bind.ident().unwrap(), ident, "{input}" );
+            assert_eq!( bind.is_mut(), is_mut, "{input}" );
+        }
+    }
+
+    #[test]
+    fn expr_works() {
+        for ( input, expr ) in [
+            ( "a"                 , None                    ),
+            ( "mut a = b"         , None                    ),
+            ( "ref a = b"         , None                    ),
+            ( "rc a"              , None                    ),
+            ( "into a"            , None                    ),
+            ( "a = b.c()"         , Some( quote!( b.c() ))  ),
+            ( "mut a = b.c()"     , Some( quote!( b.c() ))  ),
+            ( "s.to_owned()"      , Some( quote!( s.to_owned() ))),
+            ( "mut s.to_owned()"  , Some( quote!( s.to_owned() ))),
+            ( "into a = b"        , Some( quote!( b ))      ),
+            ( "string a = 1"      , Some( quote!( 1 ))      ),
+            ( "a: T = b"          , Some( quote!( b ))      ),
+            ( "mut a: T = b"      , Some( quote!( b ))      ),
+        ] {
+            let bind = syn::parse_str::<Bind>( input ).unwrap();
+            assert_eq!( bind.expr().map( |expr| expr.to_token_stream().to_string() ),
+                expr.map( |expr| expr.to_string() ), "{input}" );
+        }
+    }
+
+    #[test]
+    fn clone_debug_eq_work() {
+        let bind = syn::parse_str::<Bind>( "mut a = b" ).unwrap();
+        assert_eq!( bind.clone(), bind );
+        assert_eq!( format!( "{bind:?}" ), "Bind(let mut a = b . clone () ;)" );
+
+        let reparsed = syn::parse_str::<Bind>( "mut a = b" ).unwrap();
+        assert_eq!( reparsed, bind );
+        assert_ne!( syn::parse_str::<Bind>( "a = b" ).unwrap(), bind );
+    }
+
+    #[test]
+    fn constructors_work() {
+        let parsed = |input| syn::parse_str::<Bind>( input ).unwrap();
+        let a = || format_ident!( "a" );
+        let b = || format_ident!( "b" );
+
+        assert_eq!( Bind::clone_of(     a()      ), parsed( "a"         ));
+        assert_eq!( Bind::mut_clone_of( a()      ), parsed( "mut a"     ));
+        assert_eq!( Bind::rename(       a(), b() ), parsed( "a = b"     ));
+        assert_eq!( Bind::mut_rename(   a(), b() ), parsed( "mut a = b" ));
+        assert_eq!( Bind::assign(       a(), parse_quote!( b.c() )), parsed( "a = b.c()"     ));
+        assert_eq!( Bind::mut_assign(   a(), parse_quote!( b.c() )), parsed( "mut a = b.c()" ));
+    }
+
+    #[test]
+    fn parse_binds_works() {
+        for input in [ "a, mut b, c = d", "a, mut b, c = d," ] {
+            let binds = parse_binds.parse_str( input ).unwrap();
+            assert_eq!( binds.into_iter().collect::<Vec<_>>(), vec![
+                Bind::clone_of(     format_ident!( "a" )),
+                Bind::mut_clone_of( format_ident!( "b" )),
+                Bind::rename(       format_ident!( "c" ), format_ident!( "d" )),
+            ]);
+        }
+    }
+
+    #[test]
+    fn auto_naming_prefers_receiver() {
+        for ( input, ident ) in [
+            ( "a.clone()"                     , "a"   ),
+            ( "drop(x)"                       , "x"   ),
+            ( "vec.iter().cloned().collect()" , "vec" ),
+            ( "(*foo).clone()"                , "foo" ),
+        ] {
+            assert_eq!( syn::parse_str::<Bind>( input ).unwrap().ident().unwrap(), ident, "{input}" );
+        }
+    }
+
+    #[test]
+    fn auto_naming_uses_last_field() {
+        for ( input, ident ) in [
+            ( "self.foo.clone()"             , "foo" ),
+            ( "config.db.url.to_owned()"     , "url" ),
+            ( "a.b.c.clone()"                , "c"   ),
+            ( "&self.foo"                    , "foo" ),
+        ] {
+            assert_eq!( syn::parse_str::<Bind>( input ).unwrap().ident().unwrap(), ident, "{input}" );
+        }
+        assert_eq!( parse_err( "tuple.0.clone()" ),
+            "cannot infer a binding name from a tuple index, give an explicit name with `name = expr`" );
+    }
+
+    #[test]
+    fn auto_naming_skips_conversions_only() {
+        for ( input, ident ) in [
+            ( "config.name.clone()"             , "name" ),
+            ( "a.b.c.to_owned()"                , "c"    ),
+            ( "foo.clone()"                     , "foo"  ),
+            ( "v.to_vec().into()"               , "v"    ),
+            ( "s.to_string()"                   , "s"    ),
+        ] {
+            assert_eq!( syn::parse_str::<Bind>( input ).unwrap().ident().unwrap(), ident, "{input}" );
+        }
+        for ( input, method ) in [
+            ( "foo.bar()"                       , "bar"  ),
+            ( "a.b.len()"                       , "len"  ),
+            ( "map.get(&key).cloned()"          , "get"  ),
+            ( "foo.clone_from(&bar)"            , "clone_from" ),
+            ( "s.into( x )"                     , "into" ),
+        ] {
+            assert_eq!( parse_err( input ), format!(
+                "cannot infer a binding name from a call of `{method}`, give an explicit name with `name = expr`" ));
+        }
+        assert_eq!( syn::parse_str::<Bind>( "len = a.b.len()" ).unwrap().ident().unwrap(), "len" );
+    }
+
+    #[test]
+    fn ref_mut_is_not_mut_clone() {
+        assert!( matches!( syn::parse_str::<Bind>( "ref mut foo"       ), Ok( Bind::RefMut(   .. ))));
+        assert!( matches!( syn::parse_str::<Bind>( "ref mut foo = bar" ), Ok( Bind::RefMutId( .. ))));
+        assert!( matches!( syn::parse_str::<Bind>( "mut foo"           ), Ok( Bind::MutId(    .. ))));
+    }
+
+    #[test]
+    fn pat_works() {
+        let bind = syn::parse_str::<Bind>( "mut (a, (b, ref c)) = pair" ).unwrap();
+        assert!( matches!( bind, Bind::MutPat( .. )));
+        assert_eq!( bind.ident(), None );
+        assert!( bind.is_mut() );
+        assert_eq!( bind.to_token_stream().to_string(), quote!{ let (mut a, (mut b, ref c)) = pair; }.to_string() );
+    }
+
+    #[test]
+    fn malformed_pat_is_error() {
+        assert!( syn::parse_str::<Bind>( "Point{ x, 1 } = p" ).is_err() );
+        assert!( syn::parse_str::<Bind>( "Point{ x, y } =" ).is_err() );
+        assert!( matches!( syn::parse_str::<Bind>( "Point{ x, .. } = p" ), Ok( Bind::Pat( .. ))));
+    }
+
+    #[test]
+    fn to_local_works() {
+        for ( input, expected ) in [
+            ( "a"                , quote!{ let a = a.clone(); }                        ),
+            ( "mut a = b"        , quote!{ let mut a = b.clone(); }                    ),
+            ( "a = b.c()"        , quote!{ let a = b.c(); }                            ),
+            ( "ref mut a"        , quote!{ let a = &mut a; }                           ),
+            ( "rc a = b"         , quote!{ let a = ::std::rc::Rc::clone( &b ); }       ),
+            ( "into a = b + c"   , quote!{ let a = (b + c).into(); }                   ),
+            ( "mut a: T = b"     , quote!{ let mut a: T = b; }                         ),
+            ( "mut (a, b) = c"   , quote!{ let (mut a, mut b) = c; }                   ),
+            ( "move a"           , quote!{ #[allow( clippy::redundant_locals )] let a = a; }),
+            ( "a then f( &a )"   , quote!{ let a = a.clone(); }                        ),
+            ( "#[cfg(test)] a then f( &a )", quote!{ #[cfg(test)] let a = a.clone(); }  ),
+        ] {
+            let stmt = syn::parse_str::<Bind>( input ).unwrap().to_local();
+            assert!( matches!( stmt, syn::Stmt::Local(_) ), "{input}" );
+            assert_eq!( stmt.to_token_stream().to_string(), expected.to_string(), "{input}" );
+        }
+    }
+
+    #[test]
+    fn raw_ident_works() {
+        for ( input, expected ) in [
+            ( "r#type"             , quote!{ let r#type = r#type.clone(); }  ),
+            ( "r#fn = r#type"      , quote!{ let r#fn = r#type.clone(); }    ),
+            ( "r#type.to_owned()"  , quote!{ let r#type = r#type.to_owned(); }),
+            ( "rc r#rc"            , quote!{ let r#rc = ::std::rc::Rc::clone( &r#rc ); }),
+        ] {
+            let bind = syn::parse_str::<Bind>( input ).unwrap();
+            assert_eq!( bind.to_token_stream().to_string(), expected.to_string(), "{input}" );
+        }
+    }
+
+    #[test]
+    fn semicolon_separators_work() {
+        let expected = vec![
+            Bind::clone_of(     format_ident!( "a" )),
+            Bind::mut_clone_of( format_ident!( "b" )),
+            Bind::rename(       format_ident!( "c" ), format_ident!( "d" )),
+        ];
+        for input in [ "a; mut b; c = d", "a; mut b, c = d;", "a, mut b; c = d," ] {
+            let binds = parse_binds.parse_str( input ).unwrap();
+            assert_eq!( binds.into_iter().collect::<Vec<_>>(), expected, "{input}" );
+        }
+        assert!( parse_binds.parse_str( "a;; b" ).is_err() );
+    }
+
+    #[test]
+    fn at_pattern_works() {
+        let bind = syn::parse_str::<Bind>( "x @ Some(_) = opt else { return }" ).unwrap();
+        assert!( matches!( bind, Bind::PatElse( .. )));
+        assert_eq!( bind.to_token_stream().to_string(), quote!{ let x @ Some(_) = opt else { return }; }.to_string() );
+        let bind = syn::parse_str::<Bind>( "pair @ (a, b) = p" ).unwrap();
+        assert!( matches!( bind, Bind::Pat( .. )));
+        assert_eq!( bind.to_token_stream().to_string(), quote!{ let pair @ (a, b) = p; }.to_string() );
+        assert!( matches!( syn::parse_str::<Bind>( "x = opt" ), Ok( Bind::IdId( .. ))));
+    }
+
+    #[test]
+    fn let_else_works() {
+        let bind = syn::parse_str::<Bind>( "Ok((a, b)) = res else { return }" ).unwrap();
+        assert!( matches!( bind, Bind::PatElse( .. )));
+        assert_eq!( bind.to_token_stream().to_string(), quote!{ let Ok((a, b)) = res else { return }; }.to_string() );
+        assert_eq!( parse_err( "mut Some(x) = opt else { return }" ),
+            "`mut` is not supported with `else`, use `mut` inside the pattern instead" );
+    }
+
+    #[test]
+    fn source_ident_works() {
+        for ( input, source ) in [
+            ( "a"                 , Some( "a" )),
+            ( "mut a"             , Some( "a" )),
+            ( "a = b"             , Some( "b" )),
+            ( "mut a = b"         , Some( "b" )),
+            ( "ref a = b"         , Some( "b" )),
+            ( "ref mut a"         , Some( "a" )),
+            ( "rc a = b"          , Some( "b" )),
+            ( "arc a"             , Some( "a" )),
+            ( "weak a = b"        , Some( "b" )),
+            ( "move a = b"        , Some( "b" )),
+            ( "copy a"            , Some( "a" )),
+            ( "into a"            , Some( "a" )),
+            ( "string a"          , Some( "a" )),
+            ( "into a = b"        , None       ),
+            ( "a = b.c()"         , None       ),
+            ( "s.to_owned()"      , None       ),
+            ( "a: T = b"          , None       ),
+            ( "(a, b) = c"        , None       ),
+            ( "try a = b"         , None       ),
+        ] {
+            let bind = syn::parse_str::<Bind>( input ).unwrap();
+            assert_eq!( bind.source_ident().map( ToString::to_string ).as_deref(), source, "{input}" );
+        }
+    }
+
+    #[test]
+    fn redundant_clone_is_noted() {
+        for input in [ "foo.clone()", "mut foo.clone()", "foo.clone().clone()" ] {
+            let tokens = syn::parse_str::<Bind>( input ).unwrap().to_token_stream().to_string();
+            assert!( tokens.contains( "deprecated" ), "{input}" );
+        }
+        for input in [ "foo", "foo.to_owned()", "foo.bar.clone()", "foo.clone().to_string()" ] {
+            let tokens = syn::parse_str::<Bind>( input ).unwrap().to_token_stream().to_string();
+            assert!( !tokens.contains( "deprecated" ), "{input}" );
+        }
+    }
+
+    #[test]
+    fn deref_clone_works() {
+        let bind = syn::parse_str::<Bind>( "clone *self.foo" ).unwrap();
+        assert!( matches!( bind, Bind::DerefClone( .. )));
+        assert_eq!( bind.to_token_stream().to_string(), quote!{ let foo = (*self.foo).clone(); }.to_string() );
+        assert_eq!( parse_err( "clone *a + b" ), "expected `clone *expr`" );
+    }
+
+    #[test]
+    fn std_feature_selects_paths() {
+        let tokens = syn::parse_str::<Bind>( "arc a" ).unwrap().to_token_stream().to_string();
+        assert_eq!( tokens, quote!{ let a = ::std::sync::Arc::clone( &a ); }.to_string() );
+    }
+
+    #[test]
+    fn sole_ident_of_exprs() {
+        let id = |src| bind_syn::sole_ident( &syn::parse_str( src ).unwrap() ).map( |id| id.to_string() );
+        assert_eq!( id( "foo"                ).as_deref(), Some( "foo" ));
+        assert_eq!( id( "foo.bar().baz()"    ).as_deref(), Some( "foo" ));
+        assert_eq!( id( "a + 1"              ).as_deref(), Some( "a"   ));
+        assert_eq!( id( "a + b"              ), None );
+        assert_eq!( id( "std::mem::take"     ), None );
+        assert_eq!( id( "Config{ timeout }"  ), None );
+        assert_eq!( id( "vec![x]"            ), None );
+        assert_eq!( id( "if c { a } else { b }" ), None );
+        assert_eq!( id( "1 + 2"              ), None );
+    }
+
+    #[test]
+    fn from_str_works() {
+        for ( input, expected ) in [
+            ( "foo"                  , quote!{ let foo = foo.clone(); }          ),
+            ( "mut foo"              , quote!{ let mut foo = foo.clone(); }      ),
+            ( "bar = foo"            , quote!{ let bar = foo.clone(); }          ),
+            ( "mut bar = foo"        , quote!{ let mut bar = foo.clone(); }      ),
+            ( "bar = foo + 1"        , quote!{ let bar = foo + 1; }              ),
+            ( "mut bar = foo + 1"    , quote!{ let mut bar = foo + 1; }          ),
+            ( "foo.to_owned()"       , quote!{ let foo = foo.to_owned(); }       ),
+            ( "mut foo.to_owned()"   , quote!{ let mut foo = foo.to_owned(); }   ),
+        ] {
+            let bind = input.parse::<Bind>().unwrap();
+            assert_eq!( bind.to_token_stream().to_string(), expected.to_string(), "{input}" );
+        }
+        assert!( "= foo".parse::<Bind>().is_err() );
+    }
+
+    #[test]
+    fn attributes_work() {
+        let bind = syn::parse_str::<Bind>( "#[cfg(test)] mut foo" ).unwrap();
+        assert_eq!( bind.to_token_stream().to_string(), quote!{ #[cfg(test)] let mut foo = foo.clone(); }.to_string() );
+        assert_eq!( bind.ident().unwrap(), "foo" );
+        assert!( bind.is_mut() );
+        assert_eq!( parse_err( "mut #[cfg(test)] foo" ), "attributes go before `mut`" );
+    }
+
+    #[test]
+    fn bind_list_works() {
+        let mut binds = bind_syn::BindList::new();
+        binds.push( Bind::clone_of( format_ident!( "a" )));
+        binds.extend([ Bind::mut_rename( format_ident!( "b" ), format_ident!( "c" ))]);
+        assert_eq!( binds.len(), 2 );
+        assert_eq!( binds.to_token_stream().to_string(),
+            quote!{ let a = a.clone(); let mut b = c.clone(); }.to_string() );
+
+        let block = binds.to_block( parse_quote!( a + b ));
+        assert_eq!( block.to_token_stream().to_string(),
+            quote!{{ let a = a.clone(); let mut b = c.clone(); a + b }}.to_string() );
+    }
+
+    #[test]
+    fn mut_belongs_to_one_bind() {
+        let expected = vec![
+            Bind::mut_rename( format_ident!( "a" ), format_ident!( "b" )),
+            Bind::clone_of(   format_ident!( "c" )),
+        ];
+        for input in [ "mut a = b, c", "mut a = b; c" ] {
+            let binds = parse_binds.parse_str( input ).unwrap();
+            assert_eq!( binds.into_iter().collect::<Vec<_>>(), expected, "{input}" );
+        }
+        let binds = parse_binds.parse_str( "c, mut a = b" ).unwrap();
+        assert!( !binds[0].is_mut() && binds[1].is_mut() );
+    }
+
+    #[test]
+    fn rename_target_works() {
+        for ( input, expected ) in [
+            ( "foo"                  , quote!{ let x = foo.clone(); }                        ),
+            ( "mut foo"              , quote!{ let mut x = foo.clone(); }                    ),
+            ( "bar = foo"            , quote!{ let x = foo.clone(); }                        ),
+            ( "mut bar = foo"        , quote!{ let mut x = foo.clone(); }                    ),
+            ( "bar = foo + 1"        , quote!{ let x = foo + 1; }                            ),
+            ( "mut bar = foo + 1"    , quote!{ let mut x = foo + 1; }                        ),
+            ( "foo.to_owned()"       , quote!{ let x = foo.to_owned(); }                     ),
+            ( "mut foo.to_owned()"   , quote!{ let mut x = foo.to_owned(); }                 ),
+            ( "ref foo"              , quote!{ let x = &foo; }                               ),
+            ( "ref bar = foo"        , quote!{ let x = &foo; }                               ),
+            ( "ref mut foo"          , quote!{ let x = &mut foo; }                           ),
+            ( "ref mut bar = foo"    , quote!{ let x = &mut foo; }                           ),
+            ( "rc foo"               , quote!{ let x = ::std::rc::Rc::clone( &foo ); }       ),
+            ( "rc bar = foo"         , quote!{ let x = ::std::rc::Rc::clone( &foo ); }       ),
+            ( "arc foo"              , quote!{ let x = ::std::sync::Arc::clone( &foo ); }    ),
+            ( "arc bar = foo"        , quote!{ let x = ::std::sync::Arc::clone( &foo ); }    ),
+            ( "weak foo"             , quote!{ let x = ::std::rc::Rc::downgrade( &foo ); }   ),
+            ( "weak bar = foo"       , quote!{ let x = ::std::rc::Rc::downgrade( &foo ); }   ),
+            ( "move foo"             , quote!{ let x = foo; }                                ),
+            ( "move bar = foo"       , quote!{ let x = foo; }                                ),
+            ( "into foo"             , quote!{ let x = (foo).into(); }                       ),
+            ( "into bar = foo + 1"   , quote!{ let x = (foo + 1).into(); }                   ),
+            ( "string foo"           , quote!{ let x = (foo).to_string(); }                  ),
+            ( "string bar = foo + 1" , quote!{ let x = (foo + 1).to_string(); }              ),
+            ( "bar: u8 = foo"        , quote!{ let x: u8 = foo; }                            ),
+            ( "mut bar: u8 = foo"    , quote!{ let mut x: u8 = foo; }                        ),
+            ( "copy foo"             , quote!{ let x = *foo; }                               ),
+            ( "copy bar = foo"       , quote!{ let x = *foo; }                               ),
+            ( "try bar = foo"        , quote!{ let x = (foo)?; }                             ),
+            ( "qclone foo"           , quote!{ let x = ::core::clone::Clone::clone( &foo ); }),
+            ( "qclone bar = foo"     , quote!{ let x = ::core::clone::Clone::clone( &foo ); }),
+            ( "clone *foo"           , quote!{ let x = (*foo).clone(); }                     ),
+            ( "await bar = foo"      , quote!{ let x = (foo).await; }                        ),
+            ( "pin bar = foo"        , quote!{ #[allow( unused_mut )] let mut x = ::core::pin::pin!( foo ); }),
+            ( "dbg foo"              , quote!{ let x = ::std::dbg!( foo.clone() ); }         ),
+            ( "dbg bar = foo + 1"    , quote!{ let x = ::std::dbg!( foo + 1 ); }             ),
+            ( "own foo"              , quote!{ let x = foo.to_owned(); }                     ),
+            ( "own bar = foo"        , quote!{ let x = foo.to_owned(); }                     ),
+            ( "borrow_mut foo"       , quote!{ #[allow( unused_mut )] let mut x = foo.borrow_mut(); }),
+            ( "#[cfg(test)] foo"     , quote!{ #[cfg(test)] let x = foo.clone(); }           ),
+            ( "(a, b) = foo"         , quote!{ let (a, b) = foo; }                           ),
+        ] {
+            let mut bind = syn::parse_str::<Bind>( input ).unwrap();
+            bind.rename_target( format_ident!( "x" ));
+            assert_eq!( bind.to_token_stream().to_string(), expected.to_string(), "{input}" );
+        }
+    }
+
+    #[test]
+    fn duplicate_binding_is_error() {
+        let err = parse_binds.parse_str( "a, mut b, a = c" ).map( drop ).unwrap_err();
+        assert_eq!( err.to_string(), "binding `a` is defined twice" );
+        assert!( parse_binds.parse_str( "a, b = a, c = a" ).is_ok() );
+        for input in [ "copyok a, a = b", "a, copyok a = b", "copyok a, copyok a", "#[allow( unused )] a, a" ] {
+            assert_eq!( parse_binds.parse_str( input ).map( drop ).unwrap_err().to_string(),
+                "binding `a` is defined twice", "{input}" );
+        }
+        assert!( parse_binds.parse_str( "#[cfg( test )] a = 1, #[cfg( not( test ))] a = 2" ).is_ok() );
+        assert!( parse_binds.parse_str( "#[cfg( test )] copyok a, a = b" ).is_ok() );
+        for input in [ "keep a, keep a", "keep a, _a = a", "_a = a, keep a" ] {
+            assert_eq!( parse_binds.parse_str( input ).map( drop ).unwrap_err().to_string(),
+                "binding `_a` is defined twice", "{input}" );
+        }
+        assert!( parse_binds.parse_str( "keep a, a" ).is_ok() );
+    }
+
+    #[test]
+    fn self_fields_work() {
+        let binds = parse_binds.parse_str( "a, self.{ b, mut c }; d" ).unwrap();
+        let expected = [ "a", "b = self.b.clone()", "mut c = self.c.clone()", "d" ]
+            .map( |input| syn::parse_str::<Bind>( input ).unwrap() );
+        assert_eq!( binds.into_iter().collect::<Vec<_>>(), expected );
+        assert_eq!( parse_binds.parse_str( "self.{ a, a }" ).map( drop ).unwrap_err().to_string(),
+            "binding `a` is defined twice" );
+    }
+
+    #[test]
+    fn clone_group_works() {
+        let binds = parse_binds.parse_str( "clone{ a, b, c }, d = e + 1, clone{ f }, clone *g" ).unwrap();
+        let expected = [ "a", "b", "c", "d = e + 1", "f", "clone *g" ]
+            .map( |input| syn::parse_str::<Bind>( input ).unwrap() );
+        assert_eq!( binds.into_iter().collect::<Vec<_>>(), expected );
+        assert_eq!( parse_binds.parse_str( "clone{ a, b }, a" ).map( drop ).unwrap_err().to_string(),
+            "binding `a` is defined twice" );
+    }
+
+    #[test]
+    fn fan_out_works() {
+        let binds = parse_binds.parse_str( "{ a, b } = src, c" ).unwrap();
+        let expected = [ "a = src", "b = src", "c" ].map( |input| syn::parse_str::<Bind>( input ).unwrap() );
+        assert_eq!( binds.into_iter().collect::<Vec<_>>(), expected );
+        assert_eq!( parse_binds.parse_str( "{ a, b } = src.len()" ).map( drop ).unwrap_err().to_string(),
+            "expected an identifier to clone into each of the names in braces" );
+    }
+
+    #[test]
+    fn binding_modes_work() {
+        for ( input, expected ) in [
+            ( "foo"             , quote!{ let foo = foo.clone(); }     ),
+            ( "mut foo"         , quote!{ let mut foo = foo.clone(); } ),
+            ( "ref foo"         , quote!{ let foo = &foo; }            ),
+            ( "ref mut foo"     , quote!{ let foo = &mut foo; }        ),
+            ( "bar = foo"       , quote!{ let bar = foo.clone(); }     ),
+            ( "mut bar = foo"   , quote!{ let mut bar = foo.clone(); } ),
+            ( "ref bar = foo"   , quote!{ let bar = &foo; }            ),
+            ( "ref mut bar = foo", quote!{ let bar = &mut foo; }       ),
+        ] {
+            let bind = syn::parse_str::<Bind>( input ).unwrap();
+            assert_eq!( bind.to_token_stream().to_string(), expected.to_string(), "{input}" );
+        }
+
+        for ( input, err ) in [
+            ( "mut ref foo"     , "`ref` goes before `mut`, as in `ref mut id`" ),
+            ( "mut mut foo"     , "duplicated `mut`" ),
+            ( "ref mut mut foo" , "duplicated `mut`" ),
+            ( "mut rc foo"      , "`mut` is not supported with keyword-prefixed bindings" ),
+            ( "ref ref foo"     , "expected identifier, found keyword `ref`" ),
+            ( "ref foo.bar"     , "unexpected token" ),
+        ] {
+            assert_eq!( parse_err( input ), err, "{input}" );
+        }
+    }
+
+    #[test]
+    fn deeply_nested_rhs_works() {
+        let depth = 32;
+        let rhs = format!( "{}foo{}", "(1 + ".repeat( depth ), ")".repeat( depth ));
+        let bind = syn::parse_str::<Bind>( &format!( "bar = {rhs}" )).unwrap();
+        assert_eq!( bind.ident().unwrap(), "bar" );
+        assert_eq!( bind.expr().unwrap().to_token_stream().to_string(),
+            syn::parse_str::<syn::Expr>( &rhs ).unwrap().to_token_stream().to_string() );
+    }
+
+    #[test]
+    fn try_parse_input_works() {
+        let ( binds, expr ) = bind_syn::try_parse_input( quote!{ [ a; mut b = c ] move || a + b }).unwrap();
+        assert_eq!( binds, vec![
+            Bind::clone_of(   format_ident!( "a" )),
+            Bind::mut_rename( format_ident!( "b" ), format_ident!( "c" )),
+        ]);
+        assert_eq!( expr.to_token_stream().to_string(), quote!{ move | | a + b }.to_string() );
+
+        for tokens in [
+            quote!{ a move || a },
+            quote!{ ( a ) },
+            quote!{ ( 1 + 2 ) 3 },
+            quote!{ { let x = 1; } ( a ) a },
+        ] {
+            assert!( bind_syn::try_parse_input( tokens.clone() ).is_err(), "{tokens}" );
+        }
+        assert_eq!( bind_syn::try_parse_input( quote!{ borrow ( a ) || a.len() }).map( drop ).unwrap_err().to_string(),
+            "`borrow` is not supported, parse `BindInput` instead" );
+    }
+
+    #[test]
+    fn needs_clone_works() {
+        for ( input, needs_clone ) in [
+            ( "foo"                  , true  ),
+            ( "mut foo"              , true  ),
+            ( "bar = foo"            , true  ),
+            ( "mut bar = foo"        , true  ),
+            ( "bar = foo.clone()"    , false ),
+            ( "mut bar = foo + 1"    , false ),
+            ( "foo.to_owned()"       , false ),
+            ( "mut foo.to_owned()"   , false ),
+            ( "ref foo"              , false ),
+            ( "ref bar = foo"        , false ),
+            ( "ref mut foo"          , false ),
+            ( "ref mut bar = foo"    , false ),
+            ( "rc foo"               , false ),
+            ( "rc bar = foo"         , false ),
+            ( "arc foo"              , false ),
+            ( "arc bar = foo"        , false ),
+            ( "weak foo"             , false ),
+            ( "weak bar = foo"       , false ),
+            ( "move foo"             , false ),
+            ( "take bar = foo"       , false ),
+            ( "into foo"             , false ),
+            ( "into bar = foo + 1"   , false ),
+            ( "string foo"           , false ),
+            ( "string bar = foo + 1" , false ),
+            ( "bar: u8 = foo"        , false ),
+            ( "mut bar: u8 = foo"    , false ),
+            ( "(a, b) = foo"         , false ),
+            ( "mut (a, b) = foo"     , false ),
+            ( "copy foo"             , false ),
+            ( "copy bar = foo"       , false ),
+            ( "try bar = foo"        , false ),
+            ( "await bar = foo"      , false ),
+            ( "pin bar = foo"        , false ),
+            ( "keep foo"             , true  ),
+            ( "Some(a) = foo else { return }", false ),
+            ( "qclone foo"           , true  ),
+            ( "qclone bar = foo"     , true  ),
+            ( "clone *foo"           , true  ),
+            ( "#[cfg(test)] foo"     , true  ),
+            ( "#[cfg(test)] ref foo" , false ),
+        ] {
+            assert_eq!( syn::parse_str::<Bind>( input ).unwrap().needs_clone(), needs_clone, "{input}" );
+        }
+    }
+
+    #[test]
+    fn cast_works() {
+        for ( input, expected ) in [
+            ( "cast n: usize = a + b" , quote!{ let n = (a + b) as usize; } ),
+            ( "cast n: i64"           , quote!{ let n = (n) as i64; }       ),
+        ] {
+            let bind = syn::parse_str::<Bind>( input ).unwrap();
+            assert_eq!( bind.to_token_stream().to_string(), expected.to_string(), "{input}" );
+        }
+        assert!( syn::parse_str::<Bind>( "cast n = a" ).is_err() );
+    }
+
+    #[test]
+    fn rename_refs_works() {
+        let ( from, to ) = ( format_ident!( "a" ), format_ident!( "b" ));
+        for ( input, expected ) in [
+            ( "x = a + a.len()"                 , quote!{ let x = b + b.len(); }                    ),
+            ( "x = Foo{ a, c: a.c }"            , quote!{ let x = Foo{ a: b, c: b.c }; }            ),
+            ( "x = c.a + f(a)"                  , quote!{ let x = c.a + f(b); }                     ),
+            ( "x = a.map( |a| a + 1 )"          , quote!{ let x = b.map( |a| a + 1 ); }             ),
+            ( "x = { let c = a; let a = c; a }" , quote!{ let x = { let c = b; let a = c; a }; }    ),
+            ( "x = match a { Some(a) => a, None => a.len() }",
+                quote!{ let x = match b { Some(a) => a, None => b.len() }; }                         ),
+            ( "#[cfg(test)] x: u8 = a"          , quote!{ #[cfg(test)] let x: u8 = b; }             ),
+            ( "a"                               , quote!{ let a = a.clone(); }                      ),
+        ] {
+            let mut bind = syn::parse_str::<Bind>( input ).unwrap();
+            bind.rename_refs( &from, &to );
+            assert_eq!( bind.to_token_stream().to_string(), expected.to_string(), "{input}" );
+        }
+    }
+
+    #[test]
+    fn malformed_binds_are_all_reported() {
+        let err = parse_binds.parse_str( "= foo, bar, mut mut baz; qux quux, a, a" ).map( drop ).unwrap_err();
+        let messages = err.into_iter().map( |err| err.to_string() ).collect::<Vec<_>>();
+        assert_eq!( messages, [
+            "expected an expression",
+            "duplicated `mut`",
+            "expected `,` or `;`",
+            "binding `a` is defined twice",
+        ]);
+    }
+
+    #[test]
+    fn binding_mode_classifies_each_form() {
+        use bind_syn::BindingMode;
+
+        for ( input, mode ) in [
+            ( "foo"                  , BindingMode::Clone   ),
+            ( "mut foo"              , BindingMode::Clone   ),
+            ( "bar = foo"            , BindingMode::Clone   ),
+            ( "mut bar = foo"        , BindingMode::Clone   ),
+            ( "bar = foo + 1"        , BindingMode::Expr    ),
+            ( "mut bar = foo + 1"    , BindingMode::Expr    ),
+            ( "foo.to_owned()"       , BindingMode::Expr    ),
+            ( "mut foo.to_owned()"   , BindingMode::Expr    ),
+            ( "ref foo"              , BindingMode::Ref     ),
+            ( "ref bar = foo"        , BindingMode::Ref     ),
+            ( "ref mut foo"          , BindingMode::RefMut  ),
+            ( "ref mut bar = foo"    , BindingMode::RefMut  ),
+            ( "rc foo"               , BindingMode::Clone   ),
+            ( "rc bar = foo"         , BindingMode::Clone   ),
+            ( "arc foo"              , BindingMode::Clone   ),
+            ( "arc bar = foo"        , BindingMode::Clone   ),
+            ( "weak foo"             , BindingMode::Clone   ),
+            ( "weak bar = foo"       , BindingMode::Clone   ),
+            ( "move foo"             , BindingMode::Move    ),
+            ( "move bar = foo"       , BindingMode::Move    ),
+            ( "take bar = foo"       , BindingMode::Move    ),
+            ( "into foo"             , BindingMode::Move    ),
+            ( "into bar = foo + 1"   , BindingMode::Expr    ),
+            ( "string foo"           , BindingMode::Clone   ),
+            ( "string bar = foo + 1" , BindingMode::Expr    ),
+            ( "bar: u8 = foo"        , BindingMode::Expr    ),
+            ( "mut bar: u8 = foo"    , BindingMode::Expr    ),
+            ( "(a, b) = foo"         , BindingMode::Expr    ),
+            ( "mut (a, b) = foo"     , BindingMode::Expr    ),
+            ( "copy foo"             , BindingMode::Clone   ),
+            ( "copy bar = foo"       , BindingMode::Clone   ),
+            ( "try bar = foo"        , BindingMode::Expr    ),
+            ( "Some(a) = foo else { return }", BindingMode::Expr ),
+            ( "qclone foo"           , BindingMode::Clone   ),
+            ( "qclone bar = foo"     , BindingMode::Clone   ),
+            ( "clone *foo"           , BindingMode::Clone   ),
+            ( "await bar = foo"      , BindingMode::Expr    ),
+            ( "cast bar: u8 = foo"   , BindingMode::Expr    ),
+            ( "pin bar = foo"        , BindingMode::Expr    ),
+            ( "dbg bar = foo"        , BindingMode::Expr    ),
+            ( "keep foo"             , BindingMode::Clone   ),
+            ( "own foo"              , BindingMode::Clone   ),
+            ( "own bar = foo"        , BindingMode::Clone   ),
+            ( "borrow_mut foo"       , BindingMode::RefMut  ),
+            ( "borrow_mut bar = foo" , BindingMode::RefMut  ),
+            ( "#[cfg(test)] foo"     , BindingMode::Clone   ),
+            ( "#[cfg(test)] ref foo" , BindingMode::Ref     ),
+        ] {
+            let bind = syn::parse_str::<Bind>( input ).unwrap();
+            assert_eq!( bind.binding_mode(), mode, "{input}" );
+        }
+    }
+
+    #[test]
+    fn keep_prefixes_underscore() {
+        for ( input, expected ) in [
+            ( "keep guard"  , quote!{ let _guard = guard.clone(); }  ),
+            ( "keep r#type" , quote!{ let _type = r#type.clone(); }  ),
+        ] {
+            let bind = syn::parse_str::<Bind>( input ).unwrap();
+            assert_eq!( bind.ident(), None );
+            assert_eq!( bind.to_token_stream().to_string(), expected.to_string(), "{input}" );
+        }
+    }
+
+    #[test]
+    fn shadowed_param_is_error() {
+        let parse = |input: &str| syn::parse_str::<bind_syn::BindInput>( input ).map( drop );
+        assert!( parse( "( foo ) move |foo| foo" ).is_err() );
+        assert!( parse( "( foo ) move |foo: u8| foo" ).is_err() );
+        assert!( parse( "( foo ) move |( foo, _ ): ( u8, u8 )| foo" ).is_ok() );
+        assert!( parse( "( foo ) move |bar| foo + bar" ).is_ok() );
+        assert!( parse( "( foo ) (move |foo| foo)" ).is_err() );
+        assert!( parse( "( foo ) ((|foo: u8| foo))" ).is_err() );
+    }
+
+    #[test]
+    fn bind_input_is_public() {
+        use bind_syn::{BindInput, BindList};
+        use syn::{Expr, MacroDelimiter, punctuated::Punctuated, token};
+
+        let input = syn::parse_str::<BindInput>( "borrow { let c = 1; } [ a, mut b = c ] move || a + b" ).unwrap();
+        assert_eq!( input.borrow.unwrap(), "borrow" );
+        assert_eq!( input.prelude.len(), 1 );
+        assert!( matches!( input.delimiter, MacroDelimiter::Bracket(_) ));
+        assert_eq!( input.binds.len(), 2 );
+        assert!( matches!( input.expr, Expr::Closure(_) ));
+
+        let input = BindInput{
+            borrow    : None,
+            prelude   : Vec::new(),
+            delimiter : MacroDelimiter::Paren( token::Paren::default() ),
+            binds     : Punctuated::from_iter([ syn::parse_str::<Bind>( "a" ).unwrap() ]),
+            expr      : parse_quote!( a.len() ),
+        };
+        let block = input.binds.into_iter().collect::<BindList>().to_block( input.expr );
+        assert_eq!( block.to_token_stream().to_string(), quote!{{ let a = a.clone(); a.len() }}.to_string() );
+    }
+
+    #[test]
+    fn if_and_match_round_trip() {
+        for ( input, expected ) in [
+            ( "x = if cond { a.clone() } else { b.clone() }",
+                quote!{ let x = if cond { a.clone() } else { b.clone() }; }),
+            ( "mut x = match opt { Some( a ) if a > 0 => a, _ => b }",
+                quote!{ let mut x = match opt { Some( a ) if a > 0 => a, _ => b }; }),
+            ( "x: u8 = if let Some( a ) = opt { a } else { 0 }",
+                quote!{ let x: u8 = if let Some( a ) = opt { a } else { 0 }; }),
+        ] {
+            let bind = syn::parse_str::<Bind>( input ).unwrap();
+            assert_eq!( bind.ident().unwrap(), "x" );
+            assert_eq!( bind.to_token_stream().to_string(), expected.to_string(), "{input}" );
+        }
+    }
+
+    #[test]
+    fn rc_and_arc_renames_parse() {
+        for ( input, expected ) in [
+            ( "rc shared"           , quote!{ let shared = ::std::rc::Rc::clone( &shared ); }     ),
+            ( "rc handle = shared"  , quote!{ let handle = ::std::rc::Rc::clone( &shared ); }     ),
+            ( "arc shared"          , quote!{ let shared = ::std::sync::Arc::clone( &shared ); }  ),
+            ( "arc handle = shared" , quote!{ let handle = ::std::sync::Arc::clone( &shared ); }  ),
+        ] {
+            let bind = syn::parse_str::<Bind>( input ).unwrap();
+            assert_eq!( bind.to_token_stream().to_string(), expected.to_string(), "{input}" );
+        }
+        assert!( matches!( syn::parse_str::<Bind>( "rc handle = shared"  ).unwrap(), Bind::RcId(  .. )));
+        assert!( matches!( syn::parse_str::<Bind>( "arc handle = shared" ).unwrap(), Bind::ArcId( .. )));
+        assert!( syn::parse_str::<Bind>( "rc handle = shared.clone()" ).is_err() );
+    }
+
+    #[test]
+    fn map_expr_works() {
+        let some = |expr: syn::Expr| -> syn::Expr { syn::parse_quote!( Some( #expr )) };
+        for ( input, expected ) in [
+            ( "x = a + 1"                 , quote!{ let x = Some( a + 1 ); }                ),
+            ( "mut x = a * 2"             , quote!{ let mut x = Some( a * 2 ); }            ),
+            ( "a.b"                       , quote!{ let b = Some( a.b ); }                  ),
+            ( "x: Option<u8> = 1"         , quote!{ let x: Option<u8> = Some( 1 ); }        ),
+            ( "(a, b) = c"                , quote!{ let (a, b) = Some( c ); }               ),
+            ( "try x = f()"               , quote!{ let x = ( Some( f() ))?; }              ),
+            ( "#[cfg(test)] x = a - 1"    , quote!{ #[cfg(test)] let x = Some( a - 1 ); }   ),
+        ] {
+            let bind = syn::parse_str::<Bind>( input ).unwrap().map_expr( some );
+            assert_eq!( bind.to_token_stream().to_string(), expected.to_string(), "{input}" );
+        }
+
+        for input in [ "a", "mut a", "x = a", "ref a", "rc a", "move x = a", "keep a", "#[cfg(test)] a" ] {
+            let bind = syn::parse_str::<Bind>( input ).unwrap();
+            let mapped = bind.clone().map_expr( |_| unreachable!() );
+            assert_eq!( mapped, bind, "{input}" );
+        }
+    }
+
+    #[test]
+    fn prelude_shadow_is_error() {
+        let parse = |input: &str| syn::parse_str::<bind_syn::BindInput>( input ).map( drop );
+        let err = parse( "{ let n = 2; } ( n = 3 ) n" ).unwrap_err();
+        assert_eq!( err.to_string(), "binding `n` is defined twice, in the prelude and in the bindings" );
+        assert!( parse( "{ let ( a, mut b ) = ( 1, 2 ); } ( c, b ) b" ).is_err() );
+        assert!( parse( "{ let n: u8 = 2; } ( #[cfg(test)] n = 1 ) n" ).is_err() );
+        assert!( parse( "{ let n = 2; } ( m = n + 1 ) m" ).is_ok() );
+        assert!( parse( "{ let n = 2; } ( (a, b) = (n, n) ) a + b" ).is_ok() );
+    }
+
+    #[test]
+    fn priority_orders_binds() {
+        let order = |input: &str| parse_binds.parse_str( input ).unwrap()
+            .iter().map( |bind| bind.ident().unwrap().to_string() ).collect::<Vec<_>>();
+        assert_eq!( order( "@2 a, @1 b" ), [ "b", "a" ]);
+        assert_eq!( order( "@1 a, b, @1 c; d" ), [ "b", "d", "a", "c" ]);
+        assert_eq!( order( "@3 a, @1 clone{ b, c }, @2 { d, e } = f, g" ), [ "g", "b", "c", "d", "e", "a" ]);
+        assert_eq!( order( "@1 #[cfg(test)] a = 1, mut b = 2," ), [ "b", "a" ]);
+        assert_eq!( order( "a, b, c" ), [ "a", "b", "c" ]);
+
+        let err = parse_binds.parse_str( "@ a, @x b, @1 c" ).map( drop ).unwrap_err();
+        let messages = err.into_iter().map( |err| err.to_string() ).collect::<Vec<_>>();
+        assert_eq!( messages, [
+            "expected an integer priority after `@`, as in `@1 id`",
+            "expected an integer priority after `@`, as in `@1 id`",
+        ]);
+    }
+
+    #[test]
+    fn binds_parse_and_emit() {
+        use bind_syn::{BindList, Binds};
+
+        let binds = syn::parse2::<Binds>( quote!{ a, mut b = c, d = e + 1, }).unwrap();
+        assert_eq!( binds.0.len(), 3 );
+        assert_eq!( binds.to_token_stream().to_string(),
+            quote!{ let a = a.clone(); let mut b = c.clone(); let d = e + 1; }.to_string() );
+
+        let reparsed = syn::parse2::<Binds>( quote!{ a, mut b = c, d = e + 1 }).unwrap();
+        assert_eq!( reparsed, binds );
+        assert_eq!( BindList::from( binds ).len(), 3 );
+
+        assert_eq!( syn::parse2::<Binds>( quote!{} ).unwrap(), Binds::default() );
+        assert!( syn::parse2::<Binds>( quote!{ a b } ).is_err() );
+    }
+
+    #[test]
+    fn prelude_exports_work() {
+        use bind_syn::prelude::*;
+
+        let bind: Bind = "mut a = b".parse().unwrap();
+        assert_eq!( bind.binding_mode(), BindingMode::Clone );
+
+        let binds = parse_binds.parse_str( "a, b = c + 1" ).unwrap();
+        let list = binds.into_iter().collect::<BindList>();
+        let parsed = syn::parse_str::<Binds>( "a, b = c + 1" ).unwrap();
+        assert_eq!( BindList::from( parsed ), list );
+
+        let input = syn::parse_str::<BindInput>( "( a ) move || a" ).unwrap();
+        assert_eq!( input.binds.len(), 1 );
+        assert_eq!( try_parse_input( quote!{ ( a ) move || a }).unwrap().0.len(), 1 );
+
+        assert_eq!( sole_ident( &parse_quote!( a + 1 )).unwrap(), "a" );
+        assert!( matches!( classify_expr( parse_quote!( a )), ExprOrIdent::Ident(_) ));
+        assert!( !clone_fields( &parse_quote!( self ), &[ format_ident!( "a" )]).is_empty() );
+        assert!( matches!( unparenthesized( &parse_quote!( ((|| a)) )), syn::Expr::Closure(_) ));
+    }
+
+    #[test]
+    fn then_stmt_works() {
+        for ( input, expected ) in [
+            ( "mut v = src then v.reserve( 10 )"      , quote!{ let mut v = src.clone(); v.reserve( 10 ); }               ),
+            ( "a then f( &a ) then g()"               , quote!{ let a = a.clone(); f( &a ); g(); }                         ),
+            ( "#[cfg(test)] a then f( &a )"           , quote!{ #[cfg(test)] let a = a.clone(); #[cfg(test)] f( &a ); }    ),
+            ( "(x, y) = p then f( x )"                , quote!{ let (x, y) = p; f( x ); }                                  ),
+        ] {
+            let bind = syn::parse_str::<Bind>( input ).unwrap();
+            assert_eq!( bind.to_token_stream().to_string(), expected.to_string(), "{input}" );
+        }
+
+        let mut bind = syn::parse_str::<Bind>( "mut v = src then v.reserve( 10 )" ).unwrap();
+        assert!( matches!( bind, Bind::ThenStmt(..) ));
+        assert_eq!( bind.ident().unwrap(), "v" );
+        assert!( bind.is_mut() );
+        assert_eq!( bind.source_ident().unwrap(), "src" );
+        assert_eq!( bind.to_local().to_token_stream().to_string(), quote!{ let mut v = src.clone(); }.to_string() );
+        assert_eq!( bind.then_stmts().len(), 1 );
+        let stmts = bind.to_stmts();
+        assert_eq!( quote!{ #(#stmts)* }.to_string(), quote!{ let mut v = src.clone(); v.reserve( 10 ); }.to_string() );
+
+        bind.rename_target( format_ident!( "w" ));
+        assert_eq!( bind.to_token_stream().to_string(), quote!{ let mut w = src.clone(); w.reserve( 10 ); }.to_string() );
+
+        let binds = parse_binds.parse_str( "a then f(), b" ).unwrap();
+        assert_eq!( binds.len(), 2 );
+        assert!( parse_binds.parse_str( "a then" ).is_err() );
+    }
+
+    #[test]
+    fn clone_fields_works() {
+        use bind_syn::clone_fields;
+
+        let fields = [ format_ident!( "a" ), format_ident!( "b" )];
+        for ( receiver, expected ) in [
+            ( quote!{ self }          , quote!{ let a = self.a.clone(); let b = self.b.clone(); }                 ),
+            ( quote!{ self.handles }  , quote!{ let a = self.handles.a.clone(); let b = self.handles.b.clone(); } ),
+            ( quote!{ ctx.get() }     , quote!{ let a = ctx.get().a.clone(); let b = ctx.get().b.clone(); }       ),
+            ( quote!{ *handles }      , quote!{ let a = (*handles).a.clone(); let b = (*handles).b.clone(); }     ),
+        ] {
+            let receiver = syn::parse2::<syn::Expr>( receiver ).unwrap();
+            assert_eq!( clone_fields( &receiver, &fields ).to_string(), expected.to_string() );
+        }
+        assert!( clone_fields( &parse_quote!( self ), &[] ).is_empty() );
+    }
 }
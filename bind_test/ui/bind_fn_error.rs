@@ -0,0 +1,10 @@
+use bind::bind_fn;
+
+#[bind_fn( a = )]
+fn len( a: &str ) -> usize {
+    a.len()
+}
+
+fn main() {
+    let _ = len( "a" );
+}
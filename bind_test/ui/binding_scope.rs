@@ -0,0 +1,7 @@
+use bind::bind;
+
+fn main() {
+    let foo = String::from("foo");
+    let f = bind!( (bar = foo) move || bind!( (baz = bar) move || baz.len() )() );
+    let _ = ( f(), bar, baz );
+}
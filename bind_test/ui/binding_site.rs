@@ -0,0 +1,7 @@
+use bind_test_macros::{call_site_bindings, mixed_site_bindings};
+
+fn main() {
+    call_site_bindings!( a = 1 );
+    mixed_site_bindings!( b = 2 );
+    let _ = a + b;
+}
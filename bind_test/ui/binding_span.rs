@@ -0,0 +1,8 @@
+use bind::bind;
+
+struct NotClone;
+
+fn main() {
+    let foo = NotClone;
+    let _ = bind!( ( foo, v: String = 42 ) move || ( foo, v ));
+}
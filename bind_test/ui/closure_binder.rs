@@ -0,0 +1,6 @@
+use bind::bind;
+
+fn main() {
+    let prefix = String::from("#");
+    let _ = bind!( (prefix) for<'a> |x: &'a str| -> usize { prefix.len() + x.len() });
+}
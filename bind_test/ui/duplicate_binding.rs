@@ -0,0 +1,8 @@
+use bind::bind;
+
+fn main() {
+    let a = String::new();
+    let b = String::new();
+    let _ = bind!( (a, a = b) move || a );
+    let _ = bind!( (keep a, keep a) move || () );
+}
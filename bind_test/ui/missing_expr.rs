@@ -0,0 +1,6 @@
+use bind::bind;
+
+fn main() {
+    let foo = String::new();
+    let _ = bind!( ( foo ) );
+}
@@ -0,0 +1,8 @@
+#![deny( deprecated )]
+
+use bind::bind;
+
+fn main() {
+    let foo = String::new();
+    let _ = bind!( ( foo.clone() ) move || foo );
+}
@@ -0,0 +1,11 @@
+use bind::bind;
+
+struct Foo( String );
+
+impl Foo {
+    fn name( &self ) -> impl Fn() -> String + use<> {
+        bind!( (self.to_owned()) move || String::new() )
+    }
+}
+
+fn main() {}
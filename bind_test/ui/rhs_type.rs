@@ -0,0 +1,6 @@
+use bind::bind;
+
+fn main() {
+    let flag = true;
+    let _ = bind!( ( n = if flag { 1u8 } else { "one" }, mut v = vec![ 1u8, 'a' ]) move || { v.push( n ); v });
+}
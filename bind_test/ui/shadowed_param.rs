@@ -0,0 +1,7 @@
+use bind::bind;
+
+fn main() {
+    let foo = String::new();
+    let _ = bind!( ( foo ) move |foo: String, ( bar, _ ): ( u8, u8 )| foo.len() + bar as usize );
+    let _ = bind!( ( foo ) (move |foo: String| foo) );
+}
@@ -0,0 +1,7 @@
+use bind::bind;
+
+fn main() {
+    let other = String::from("other");
+    let f = bind!( (take s = other) move || s );
+    let _ = ( f(), other );
+}
@@ -0,0 +1,8 @@
+#![deny( unused_mut )]
+
+use bind::bind;
+
+fn main() {
+    let foo = String::new();
+    let _ = bind!( ( mut foo, mut n: usize = 0 ) move || foo.len() + n );
+}
@@ -0,0 +1,7 @@
+use bind::bind;
+
+fn main() {
+    let guard = String::from("guard");
+    let f = bind!( (keep guard) move || bind!( (n = 1) move || _guard.len() + n )() );
+    let _ = f();
+}
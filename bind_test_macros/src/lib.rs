@@ -0,0 +1,31 @@
+//! Proc macros rendering `Bind`s with `Bind::to_tokens_with_span()`, for `bind_test` to check the hygiene of the
+//! generated bindings.
+
+use bind_syn::Binds;
+
+use proc_macro::TokenStream;
+
+use proc_macro2::Span;
+
+use syn::parse_macro_input;
+
+fn bindings_with_span( input: TokenStream, span: Span ) -> TokenStream {
+    let Binds( binds ) = parse_macro_input!( input as Binds );
+    let mut tokens = proc_macro2::TokenStream::new();
+    for bind in &binds {
+        bind.to_tokens_with_span( span, &mut tokens );
+    }
+    tokens.into()
+}
+
+/// Generates the bindings at `Span::call_site()`, visible to the code following the macro call.
+#[proc_macro]
+pub fn call_site_bindings( input: TokenStream ) -> TokenStream {
+    bindings_with_span( input, Span::call_site() )
+}
+
+/// Generates the bindings at `Span::mixed_site()`, invisible to the code following the macro call.
+#[proc_macro]
+pub fn mixed_site_bindings( input: TokenStream ) -> TokenStream {
+    bindings_with_span( input, Span::mixed_site() )
+}